@@ -0,0 +1,126 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use romantic::Roman;
+
+/// Benchmarks converting every number from 1 to 3999 to a numeral.
+fn encode(c: &mut Criterion) {
+  let mut group = c.benchmark_group("encode");
+
+  let roman = Roman::default();
+  group.bench_function("to_string", |b| {
+    b.iter(|| {
+      for number in 1..=3999 {
+        black_box(roman.to_string(black_box(number)).unwrap());
+      }
+    })
+  });
+
+  let lookup = Roman::default().with_lookup_table(true);
+  group.bench_function("to_string lookup", |b| {
+    b.iter(|| {
+      for number in 1..=3999 {
+        black_box(lookup.to_string(black_box(number)).unwrap());
+      }
+    })
+  });
+
+  group.bench_function("write_to", |b| {
+    let mut output = String::with_capacity(16);
+    b.iter(|| {
+      for number in 1..=3999 {
+        output.clear();
+        roman.write_to(&mut output, black_box(number)).unwrap();
+        black_box(&output);
+      }
+    })
+  });
+
+  group.bench_function("write_to lookup", |b| {
+    let mut output = String::with_capacity(16);
+    b.iter(|| {
+      for number in 1..=3999 {
+        output.clear();
+        lookup.write_to(&mut output, black_box(number)).unwrap();
+        black_box(&output);
+      }
+    })
+  });
+
+  // The same system converting every digit on the fly.
+  let untabled = Roman::default().with_tables(false);
+  group.bench_function("to_string without tables", |b| {
+    b.iter(|| {
+      for number in 1..=3999 {
+        black_box(untabled.to_string(black_box(number)).unwrap());
+      }
+    })
+  });
+
+  group.finish();
+}
+
+/// Benchmarks converting every numeral from 1 to 3999 back to a number.
+fn decode(c: &mut Criterion) {
+  let mut group = c.benchmark_group("decode");
+
+  let roman = Roman::default();
+  let numerals = (1..=3999)
+    .map(|number| roman.to_string(number).unwrap())
+    .collect::<Vec<_>>();
+  let lowercase = numerals
+    .iter()
+    .map(|numeral| numeral.to_lowercase())
+    .collect::<Vec<_>>();
+
+  group.bench_function("from_str", |b| {
+    b.iter(|| {
+      for numeral in &numerals {
+        black_box(roman.from_str::<u16>(black_box(numeral)).unwrap());
+      }
+    })
+  });
+
+  group.bench_function("from_bytes", |b| {
+    b.iter(|| {
+      for numeral in &numerals {
+        let bytes = black_box(numeral.as_bytes());
+        black_box(roman.from_bytes::<u16>(bytes).unwrap());
+      }
+    })
+  });
+
+  group.bench_function("from_str_strict", |b| {
+    b.iter(|| {
+      for numeral in &numerals {
+        black_box(roman.from_str_strict::<u16>(black_box(numeral)).unwrap());
+      }
+    })
+  });
+
+  // The same system looking up every character in the map.
+  let untabled = Roman::default().with_tables(false);
+  group.bench_function("from_str without tables", |b| {
+    b.iter(|| {
+      for numeral in &numerals {
+        black_box(untabled.from_str::<u16>(black_box(numeral)).unwrap());
+      }
+    })
+  });
+
+  // Minuscule input goes through the full symbols instead of the ASCII
+  // table.
+  let minuscule = Roman::default().with_minuscule(true);
+  group.bench_function("from_str minuscule", |b| {
+    b.iter(|| {
+      for numeral in &lowercase {
+        black_box(minuscule.from_str::<u16>(black_box(numeral)).unwrap());
+      }
+    })
+  });
+
+  group.finish();
+}
+
+criterion_group!(benches, encode, decode);
+criterion_main!(benches);
@@ -0,0 +1,262 @@
+//! The [`RomanBuilder`] for creating a [`Roman`] with configurable rules.
+
+use alloc::vec::Vec;
+
+use crate::{
+  ConstructionError, Roman, DEFAULT_CHARACTER_SET, DEFAULT_PATTERN,
+  DEFAULT_RADIX,
+};
+
+/// A builder for a [`Roman`] with configurable numeral rules.
+///
+/// By default the rules are the same as a [`Roman`] created with
+/// [`Roman::new`]: units can be subtracted from the next 5 and 10 of them and
+/// units can be repeated up to 3 times.
+///
+/// ## Example
+///
+/// ```rust
+/// use romantic::RomanBuilder;
+///
+/// // Clock faces write 4 as IIII but 9 as IX.
+/// let clock = RomanBuilder::default()
+///   .subtractive_pairs(&[('I', 'X'), ('X', 'C'), ('C', 'M')])
+///   .maximum_repetitions(4)
+///   .build()
+///   .unwrap();
+///
+/// assert_eq!(clock.to_string(4).unwrap(), "IIII");
+/// assert_eq!(clock.to_string(9).unwrap(), "IX");
+/// assert_eq!(clock.from_str_strict::<i32>("IIII").unwrap(), 4);
+/// assert!(clock.from_str_strict::<i32>("IV").is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct RomanBuilder {
+  /// The characters to create the [`Roman`] with.
+  character_set: Vec<char>,
+
+  /// Whether subtractive pairs are allowed at all.
+  subtraction: bool,
+
+  /// The pairs of characters that can be subtracted from one another.
+  subtractive_pairs: Option<Vec<(char, char)>>,
+
+  /// The number of times a repeatable character can appear in a row.
+  maximum_repetitions: Option<usize>,
+
+  /// The characters that can be repeated.
+  repeatable: Option<Vec<char>>,
+
+  /// The number each group of characters is multiplied by.
+  radix: usize,
+
+  /// The values of the characters within each group.
+  pattern: Vec<usize>,
+
+  /// The explicit values of the characters, replacing the character set.
+  values: Option<Vec<(char, usize)>>,
+}
+
+impl Default for RomanBuilder {
+  fn default() -> Self {
+    Self::new(&DEFAULT_CHARACTER_SET)
+  }
+}
+
+impl RomanBuilder {
+  /// Creates a new [`RomanBuilder`] using the characters in `character_set`,
+  /// see [`Roman::new`] for how their magnitudes are determined.
+  pub fn new(character_set: &[char]) -> Self {
+    Self {
+      character_set: character_set.to_vec(),
+      subtraction: true,
+      subtractive_pairs: None,
+      maximum_repetitions: None,
+      repeatable: None,
+      radix: DEFAULT_RADIX,
+      pattern: DEFAULT_PATTERN.to_vec(),
+      values: None,
+    }
+  }
+
+  /// Creates a new [`RomanBuilder`] using explicit `(character, value)` pairs,
+  /// see [`Roman::new_with_values`] for how numbers are written with them.
+  ///
+  /// The [`radix`][RomanBuilder::radix] and
+  /// [`pattern`][RomanBuilder::pattern] then only determine which characters
+  /// are units and which pairs subtract by default.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::RomanBuilder;
+  ///
+  /// let roman = RomanBuilder::new_with_values(&[('I', 1), ('X', 10)])
+  ///   .maximum_repetitions(9)
+  ///   .build()
+  ///   .unwrap();
+  ///
+  /// assert_eq!(roman.to_string(28).unwrap(), "XXIIIIIIII");
+  /// ```
+  pub fn new_with_values(character_set: &[(char, usize)]) -> Self {
+    Self {
+      values: Some(character_set.to_vec()),
+      ..Self::new(&[])
+    }
+  }
+
+  /// Sets whether subtractive pairs are allowed at all.
+  ///
+  /// Without subtraction, numbers are written like in
+  /// [`Notation::Additive`][crate::Notation::Additive] and
+  /// [`Roman::from_str`] adds every character.
+  pub fn subtraction(mut self, subtraction: bool) -> Self {
+    self.subtraction = subtraction;
+    self
+  }
+
+  /// Sets the pairs of characters that can be subtracted, as the character
+  /// being subtracted followed by the character it's subtracted from.
+  ///
+  /// Each pair has to be a unit followed by the next 5 or 10 of it, like
+  /// `('I', 'V')` or `('I', 'X')`, or the next step of a custom
+  /// [`pattern`][RomanBuilder::pattern].
+  pub fn subtractive_pairs(mut self, pairs: &[(char, char)]) -> Self {
+    self.subtractive_pairs = Some(pairs.to_vec());
+    self
+  }
+
+  /// Sets the number of times a repeatable character can appear in a row.
+  ///
+  /// This defaults to 3 with subtraction and 4 without it, and a character can
+  /// always appear at least once.
+  pub fn maximum_repetitions(mut self, maximum_repetitions: usize) -> Self {
+    self.maximum_repetitions = Some(maximum_repetitions.max(1));
+    self
+  }
+
+  /// Sets the characters that can be repeated, which defaults to the units
+  /// (ie. 'I', 'X', 'C' and 'M').
+  pub fn repeatable(mut self, repeatable: &[char]) -> Self {
+    self.repeatable = Some(repeatable.to_vec());
+    self
+  }
+
+  /// Sets the number each group of characters is multiplied by, which
+  /// defaults to 10.
+  ///
+  /// Numbers are split into digits of this radix, and each digit is written
+  /// using the characters of its group.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::RomanBuilder;
+  ///
+  /// let binary = RomanBuilder::new(&['I', 'X', 'C', 'M'])
+  ///   .radix(2)
+  ///   .pattern(&[1])
+  ///   .build()
+  ///   .unwrap();
+  ///
+  /// assert_eq!(binary.to_string(13).unwrap(), "MCI");
+  /// assert_eq!(binary.from_str::<i32>("MCI").unwrap(), 13);
+  /// ```
+  pub fn radix(mut self, radix: usize) -> Self {
+    self.radix = radix;
+    self
+  }
+
+  /// Sets the values of the characters within each group, which defaults to
+  /// `[1, 5]` (ie. 'I' and 'V', 'X' and 'L').
+  ///
+  /// The pattern has to start with 1 and increase up to the radix. Units can
+  /// be subtracted from the next value in the pattern or the next unit.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::RomanBuilder;
+  ///
+  /// let duodecimal = RomanBuilder::new(&['I', 'S', 'X', 'L'])
+  ///   .radix(12)
+  ///   .pattern(&[1, 6])
+  ///   .build()
+  ///   .unwrap();
+  ///
+  /// assert_eq!(duodecimal.to_string(5).unwrap(), "IS");
+  /// assert_eq!(duodecimal.to_string(11).unwrap(), "IX");
+  /// assert_eq!(duodecimal.to_string(100).unwrap(), "LXXIIII");
+  /// assert_eq!(duodecimal.from_str::<i32>("LXXIIII").unwrap(), 100);
+  /// ```
+  pub fn pattern(mut self, pattern: &[usize]) -> Self {
+    self.pattern = pattern.to_vec();
+    self
+  }
+
+  /// Creates the [`Roman`], validating the character set like
+  /// [`Roman::try_new`] along with the radix and pattern, and checking that
+  /// every character used in the rules is part of it.
+  pub fn build(self) -> Result<Roman, ConstructionError> {
+    let mut roman = match &self.values {
+      Some(values) => {
+        Roman::validate_radix(self.radix, &self.pattern)?;
+        let mut roman = Roman::new_with_values(values)?;
+        roman.radix = self.radix;
+        roman.pattern = self.pattern.clone();
+        roman
+      }
+      None => Roman::try_new_with_radix(
+        &self.character_set,
+        self.radix,
+        &self.pattern,
+      )?,
+    };
+
+    let value_of = |character: char| {
+      roman
+        .character_magnitude_map
+        .get(&character)
+        .copied()
+        .ok_or(ConstructionError::UnknownCharacter(character))
+    };
+
+    let subtractive_pairs = match self.subtractive_pairs {
+      Some(pairs) => Some(
+        pairs
+          .into_iter()
+          .map(|(smaller, larger)| {
+            let pair = (value_of(smaller)?, value_of(larger)?);
+            if !roman.is_unit(pair.0)
+              || !roman.is_next_magnitude(pair.0, pair.1)
+            {
+              return Err(ConstructionError::InvalidSubtractivePair(
+                smaller, larger,
+              ));
+            }
+
+            Ok(pair)
+          })
+          .collect::<Result<Vec<_>, _>>()?,
+      ),
+      None => None,
+    };
+
+    let repeatable = match self.repeatable {
+      Some(repeatable) => Some(
+        repeatable
+          .into_iter()
+          .map(value_of)
+          .collect::<Result<Vec<_>, _>>()?,
+      ),
+      None => None,
+    };
+
+    roman.subtraction = self.subtraction;
+    roman.subtractive_pairs = subtractive_pairs;
+    roman.repetition_limit = self.maximum_repetitions;
+    roman.repeatable = repeatable;
+    roman.update_tables();
+    Ok(roman)
+  }
+}
@@ -0,0 +1,100 @@
+//! Converting byte slices to numbers with [`Roman::from_bytes`].
+
+use crate::{ConversionError, Roman};
+
+impl Roman {
+  /// Converts a byte slice to a generic integer [`num::PrimInt`] like
+  /// [`Roman::from_str`], without converting it to a [`str`] first.
+  ///
+  /// When the character set is ASCII and none of the options that need other
+  /// characters are enabled (like vinculum, apostrophus, Unicode input and
+  /// the styles), every byte is looked up directly. Otherwise the input has
+  /// to be valid UTF-8 and is decoded character by character.
+  ///
+  /// Errors for the input report the offset of the byte that caused them
+  /// with [`InvalidByte`][ConversionError::InvalidByte] instead of the
+  /// character.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::{ConversionError, Roman};
+  ///
+  /// let roman = Roman::default();
+  /// assert_eq!(roman.from_bytes::<u16>(b"MMXXII").unwrap(), 2022);
+  ///
+  /// assert!(matches!(
+  ///   roman.from_bytes::<u16>(b"MMXZII"),
+  ///   Err(ConversionError::InvalidByte(3))
+  /// ));
+  /// ```
+  pub fn from_bytes<T: num::PrimInt>(
+    &self,
+    input: &[u8],
+  ) -> Result<T, ConversionError> {
+    let unsigned = self
+      .negative_sign
+      .as_deref()
+      .and_then(|sign| input.strip_prefix(sign.as_bytes()));
+    let (negative, offset, input) = match unsigned {
+      Some(unsigned) => (true, input.len() - unsigned.len(), unsigned),
+      None => (false, 0, input),
+    };
+    if negative && input.is_empty() {
+      return Err(ConversionError::MissingNumeral);
+    }
+
+    let result = if self.is_nulla(input) {
+      0
+    } else if self.decodes_bytes() {
+      self.decode_ascii(input, |position, _| {
+        ConversionError::InvalidByte(offset + position)
+      })?
+    } else {
+      self.decode_utf8(input, offset)?
+    };
+
+    Self::signed(result, negative)
+  }
+
+  /// Returns whether every byte of an input can be looked up on its own,
+  /// which is when the set is ASCII and nothing needs other characters.
+  fn decodes_bytes(&self) -> bool {
+    self.tables.ascii_table()
+      && self.tables.ascii_set()
+      && !self.reads_vinculum()
+      && !self.apostrophus
+      && !self.unicode_input
+      && !self.minuscule
+      && !self.terminal_j
+  }
+
+  /// Decodes UTF-8 `input` character by character, reporting invalid
+  /// characters by their byte offset plus `offset`.
+  fn decode_utf8(
+    &self,
+    input: &[u8],
+    offset: usize,
+  ) -> Result<i128, ConversionError> {
+    let input = core::str::from_utf8(input).map_err(|error| {
+      ConversionError::InvalidByte(offset + error.valid_up_to())
+    })?;
+
+    let position_of = |index: usize| {
+      input
+        .char_indices()
+        .nth(index)
+        .map_or(input.len(), |(position, _)| position)
+    };
+
+    let symbols = self.indexed_symbols(input).map_err(|(index, error)| {
+      if let ConversionError::InvalidCharacter(_) = error {
+        return ConversionError::InvalidByte(offset + position_of(index));
+      }
+
+      error
+    })?;
+
+    self.decode_symbols(&symbols)
+  }
+}
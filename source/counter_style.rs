@@ -0,0 +1,862 @@
+//! A CSS `@counter-style` engine implementing the six counter systems from CSS
+//! Counter Styles Level 3, along with the predefined counter styles.
+
+use alloc::{
+  format,
+  string::{String, ToString},
+  vec::Vec,
+};
+
+use crate::CounterStyleError;
+
+/// The longest representation the symbolic and additive systems generate
+/// before falling back, so huge values can't allocate huge strings.
+const LENGTH_LIMIT: usize = 150;
+
+/// The name of the counter style used when no other fallback is available.
+const DECIMAL: &str = "decimal";
+
+/// The predefined counter styles, written as `@counter-style` rules.
+const PREDEFINED: &[&str] = &[
+  "@counter-style decimal {
+    system: numeric;
+    symbols: '0' '1' '2' '3' '4' '5' '6' '7' '8' '9';
+  }",
+  "@counter-style decimal-leading-zero {
+    system: numeric;
+    symbols: '0' '1' '2' '3' '4' '5' '6' '7' '8' '9';
+    pad: 2 '0';
+  }",
+  "@counter-style cjk-decimal {
+    system: numeric;
+    range: 0 infinite;
+    symbols: 〇 一 二 三 四 五 六 七 八 九;
+    suffix: '、';
+  }",
+  "@counter-style lower-alpha {
+    system: alphabetic;
+    symbols: a b c d e f g h i j k l m n o p q r s t u v w x y z;
+  }",
+  "@counter-style lower-latin {
+    system: alphabetic;
+    symbols: a b c d e f g h i j k l m n o p q r s t u v w x y z;
+  }",
+  "@counter-style upper-alpha {
+    system: alphabetic;
+    symbols: A B C D E F G H I J K L M N O P Q R S T U V W X Y Z;
+  }",
+  "@counter-style upper-latin {
+    system: alphabetic;
+    symbols: A B C D E F G H I J K L M N O P Q R S T U V W X Y Z;
+  }",
+  "@counter-style lower-greek {
+    system: alphabetic;
+    symbols: α β γ δ ε ζ η θ ι κ λ μ ν ξ ο π ρ σ τ υ φ χ ψ ω;
+  }",
+  "@counter-style upper-roman {
+    system: additive;
+    range: 1 3999;
+    additive-symbols: 1000 M, 900 CM, 500 D, 400 CD, 100 C, 90 XC, 50 L,
+      40 XL, 10 X, 9 IX, 5 V, 4 IV, 1 I;
+  }",
+  "@counter-style lower-roman {
+    system: additive;
+    range: 1 3999;
+    additive-symbols: 1000 m, 900 cm, 500 d, 400 cd, 100 c, 90 xc, 50 l,
+      40 xl, 10 x, 9 ix, 5 v, 4 iv, 1 i;
+  }",
+  "@counter-style armenian {
+    system: additive;
+    range: 1 9999;
+    additive-symbols: 9000 Ք, 8000 Փ, 7000 Ւ, 6000 Ց, 5000 Ր, 4000 Տ, 3000 Վ,
+      2000 Ս, 1000 Ռ, 900 Ջ, 800 Պ, 700 Չ, 600 Ո, 500 Շ, 400 Ն, 300 Յ,
+      200 Մ, 100 Ճ, 90 Ղ, 80 Ձ, 70 Հ, 60 Կ, 50 Ծ, 40 Խ, 30 Լ, 20 Ի, 10 Ժ,
+      9 Թ, 8 Ը, 7 Է, 6 Զ, 5 Ե, 4 Դ, 3 Գ, 2 Բ, 1 Ա;
+  }",
+  "@counter-style upper-armenian {
+    system: additive;
+    range: 1 9999;
+    additive-symbols: 9000 Ք, 8000 Փ, 7000 Ւ, 6000 Ց, 5000 Ր, 4000 Տ, 3000 Վ,
+      2000 Ս, 1000 Ռ, 900 Ջ, 800 Պ, 700 Չ, 600 Ո, 500 Շ, 400 Ն, 300 Յ,
+      200 Մ, 100 Ճ, 90 Ղ, 80 Ձ, 70 Հ, 60 Կ, 50 Ծ, 40 Խ, 30 Լ, 20 Ի, 10 Ժ,
+      9 Թ, 8 Ը, 7 Է, 6 Զ, 5 Ե, 4 Դ, 3 Գ, 2 Բ, 1 Ա;
+  }",
+  "@counter-style lower-armenian {
+    system: additive;
+    range: 1 9999;
+    additive-symbols: 9000 ք, 8000 փ, 7000 ւ, 6000 ց, 5000 ր, 4000 տ, 3000 վ,
+      2000 ս, 1000 ռ, 900 ջ, 800 պ, 700 չ, 600 ո, 500 շ, 400 ն, 300 յ,
+      200 մ, 100 ճ, 90 ղ, 80 ձ, 70 հ, 60 կ, 50 ծ, 40 խ, 30 լ, 20 ի, 10 ժ,
+      9 թ, 8 ը, 7 է, 6 զ, 5 ե, 4 դ, 3 գ, 2 բ, 1 ա;
+  }",
+  "@counter-style georgian {
+    system: additive;
+    range: 1 19999;
+    additive-symbols: 10000 ჵ, 9000 ჰ, 8000 ჯ, 7000 ჴ, 6000 ხ, 5000 ჭ,
+      4000 წ, 3000 ძ, 2000 ც, 1000 ჩ, 900 შ, 800 ყ, 700 ღ, 600 ქ, 500 ფ,
+      400 ჳ, 300 ტ, 200 ს, 100 რ, 90 ჟ, 80 პ, 70 ო, 60 ჲ, 50 ნ, 40 მ, 30 ლ,
+      20 კ, 10 ი, 9 თ, 8 ჱ, 7 ზ, 6 ვ, 5 ე, 4 დ, 3 გ, 2 ბ, 1 ა;
+  }",
+  r"@counter-style hebrew {
+    system: additive;
+    range: 1 10999;
+    additive-symbols: 10000 \5D9\5F3, 9000 \5D8\5F3, 8000 \5D7\5F3,
+      7000 \5D6\5F3, 6000 \5D5\5F3, 5000 \5D4\5F3, 4000 \5D3\5F3,
+      3000 \5D2\5F3, 2000 \5D1\5F3, 1000 \5D0\5F3, 400 \5EA, 300 \5E9,
+      200 \5E8, 100 \5E7, 90 \5E6, 80 \5E4, 70 \5E2, 60 \5E1, 50 \5E0,
+      40 \5DE, 30 \5DC, 20 \5DB, 19 \5D9\5D8, 18 \5D9\5D7, 17 \5D9\5D6,
+      16 \5D8\5D6, 15 \5D8\5D5, 10 \5D9, 9 \5D8, 8 \5D7, 7 \5D6, 6 \5D5,
+      5 \5D4, 4 \5D3, 3 \5D2, 2 \5D1, 1 \5D0;
+  }",
+  r"@counter-style disc {
+    system: cyclic;
+    symbols: \2022;
+    suffix: ' ';
+  }",
+  r"@counter-style circle {
+    system: cyclic;
+    symbols: \25E6;
+    suffix: ' ';
+  }",
+  r"@counter-style square {
+    system: cyclic;
+    symbols: \25AA;
+    suffix: ' ';
+  }",
+];
+
+/// The algorithm a [`CounterStyle`] uses to generate representations.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum System {
+  /// Cycles through the symbols, repeating them from the start.
+  Cyclic,
+
+  /// Uses each symbol once starting at the given value.
+  Fixed(i128),
+
+  /// Cycles through the symbols, doubling and tripling them on each pass.
+  Symbolic,
+
+  /// Interprets the symbols as the digits of a bijective numeral system.
+  Alphabetic,
+
+  /// Interprets the symbols as the digits of a positional numeral system.
+  Numeric,
+
+  /// Writes the value as a sum of weighted symbols, like Roman numerals.
+  Additive,
+}
+
+impl System {
+  /// Returns whether representations of negative values get the negative
+  /// sign, as opposed to falling back.
+  fn uses_negative_sign(self) -> bool {
+    !matches!(self, Self::Cyclic | Self::Fixed(_))
+  }
+
+  /// Returns the range used when the `range` descriptor is `auto`.
+  fn auto_range(self) -> (Option<i128>, Option<i128>) {
+    match self {
+      Self::Alphabetic | Self::Symbolic => (Some(1), None),
+      Self::Additive => (Some(0), None),
+      _ => (None, None),
+    }
+  }
+}
+
+/// A CSS counter style as defined by an `@counter-style` rule.
+///
+/// Use [`CounterStyle::predefined`] for the counter styles that are built into
+/// browsers, or [`CounterStyle::parse`] for your own.
+///
+/// ## Example
+///
+/// ```rust
+/// use romantic::CounterStyle;
+///
+/// let roman = CounterStyle::predefined("upper-roman").unwrap();
+/// assert_eq!(roman.format(14), "XIV");
+/// assert_eq!(roman.format(4000), "4000");
+/// assert_eq!(roman.format_marker(14), "XIV. ");
+///
+/// let custom = CounterStyle::parse(
+///   "@counter-style thumbs { system: cyclic; symbols: '👍'; suffix: ' '; }",
+/// )
+/// .unwrap();
+/// assert_eq!(custom.format_marker(3), "👍 ");
+/// ```
+#[derive(Clone, Debug)]
+pub struct CounterStyle {
+  /// The name of the counter style.
+  name: String,
+
+  /// The algorithm used to generate representations.
+  system: System,
+
+  /// The symbols used by every system except additive.
+  symbols: Vec<String>,
+
+  /// The weighted symbols used by the additive system, from the largest
+  /// weight to the smallest.
+  additive_symbols: Vec<(u128, String)>,
+
+  /// The symbols written before and after the representation of a negative
+  /// value.
+  negative: (String, String),
+
+  /// The symbol written before the representation in a marker.
+  prefix: String,
+
+  /// The symbol written after the representation in a marker.
+  suffix: String,
+
+  /// The ranges of values the counter style is used for, with [`None`] as an
+  /// infinite bound, or [`None`] for the system's default.
+  range: Option<Vec<(Option<i128>, Option<i128>)>>,
+
+  /// The minimum length of a representation and the symbol used to pad it.
+  pad: (usize, String),
+
+  /// The name of the counter style used for values it can't represent.
+  fallback: String,
+}
+
+impl CounterStyle {
+  /// Returns the predefined counter style called `name`, or [`None`] if there
+  /// is no such counter style.
+  ///
+  /// The predefined counter styles are `decimal`, `decimal-leading-zero`,
+  /// `cjk-decimal`, `lower-alpha`, `lower-latin`, `upper-alpha`,
+  /// `upper-latin`, `lower-greek`, `upper-roman`, `lower-roman`, `armenian`,
+  /// `upper-armenian`, `lower-armenian`, `georgian`, `hebrew`, `disc`,
+  /// `circle` and `square`.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::CounterStyle;
+  ///
+  /// let greek = CounterStyle::predefined("lower-greek").unwrap();
+  /// assert_eq!(greek.format(25), "αα");
+  ///
+  /// assert!(CounterStyle::predefined("klingon").is_none());
+  /// ```
+  pub fn predefined(name: &str) -> Option<Self> {
+    PREDEFINED.iter().find_map(|source| {
+      // Safe to unwrap since the predefined counter styles are always valid.
+      let style = Self::parse(source).unwrap();
+      (style.name == name).then_some(style)
+    })
+  }
+
+  /// Parses a CSS `@counter-style` rule into a [`CounterStyle`].
+  ///
+  /// Every descriptor from CSS Counter Styles Level 3 is supported except
+  /// `system: extends` and image symbols, and `speak-as` is ignored. Unlike in
+  /// a browser, any invalid descriptor returns a [`CounterStyleError`] rather
+  /// than being ignored.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::CounterStyle;
+  ///
+  /// let style = CounterStyle::parse(
+  ///   "@counter-style binary {
+  ///     system: numeric;
+  ///     symbols: '0' '1';
+  ///     pad: 4 '0';
+  ///     suffix: ') ';
+  ///   }",
+  /// )
+  /// .unwrap();
+  ///
+  /// assert_eq!(style.name(), "binary");
+  /// assert_eq!(style.format(5), "0101");
+  /// assert_eq!(style.format(-5), "-101");
+  /// assert_eq!(style.format_marker(5), "0101) ");
+  /// ```
+  pub fn parse(input: &str) -> Result<Self, CounterStyleError> {
+    let mut tokens = tokenize(input)?.into_iter().peekable();
+
+    match tokens.next() {
+      Some(Token::AtKeyword(keyword)) if keyword == "counter-style" => (),
+      _ => return Err(CounterStyleError::InvalidSyntax),
+    }
+
+    let name = match tokens.next() {
+      Some(Token::Ident(name)) if name != "none" => name,
+      _ => return Err(CounterStyleError::InvalidSyntax),
+    };
+
+    if tokens.next() != Some(Token::OpenBrace) {
+      return Err(CounterStyleError::InvalidSyntax);
+    }
+
+    let mut style = Self {
+      name,
+      system: System::Symbolic,
+      symbols: Vec::new(),
+      additive_symbols: Vec::new(),
+      negative: ("-".to_string(), String::new()),
+      prefix: String::new(),
+      suffix: ". ".to_string(),
+      range: None,
+      pad: (0, String::new()),
+      fallback: DECIMAL.to_string(),
+    };
+
+    loop {
+      let descriptor = match tokens.next() {
+        Some(Token::CloseBrace) => break,
+        Some(Token::Semicolon) => continue,
+        Some(Token::Ident(descriptor)) => descriptor,
+        _ => return Err(CounterStyleError::InvalidSyntax),
+      };
+
+      if tokens.next() != Some(Token::Colon) {
+        return Err(CounterStyleError::InvalidSyntax);
+      }
+
+      // The value goes up to the next ";", or the "}" when it's the last
+      // descriptor.
+      let mut value = Vec::new();
+      while let Some(token) = tokens
+        .next_if(|token| !matches!(token, Token::Semicolon | Token::CloseBrace))
+      {
+        value.push(token);
+      }
+
+      if tokens.peek().is_none() {
+        return Err(CounterStyleError::InvalidSyntax);
+      }
+
+      style.set_descriptor(&descriptor, value)?;
+    }
+
+    if tokens.next().is_some() {
+      return Err(CounterStyleError::InvalidSyntax);
+    }
+
+    style.validate()?;
+    Ok(style)
+  }
+
+  /// Returns the name of the counter style.
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Formats a counter `value` as its representation, without the prefix and
+  /// suffix.
+  ///
+  /// Values outside of the range or that the system can't represent use the
+  /// fallback counter style. A fallback that isn't predefined uses `decimal`
+  /// instead.
+  pub fn format<T: num::PrimInt + ToString>(&self, value: T) -> String {
+    // Values that don't fit in an `i128`, or whose absolute value doesn't,
+    // can only be represented in decimal.
+    match value.to_i128() {
+      Some(value) if value != i128::MIN => self.generate(value),
+      _ => value.to_string(),
+    }
+  }
+
+  /// Formats a counter `value` as a list marker, with the prefix and suffix
+  /// around its representation.
+  ///
+  /// The prefix and suffix always come from this counter style, even when the
+  /// representation comes from the fallback.
+  pub fn format_marker<T: num::PrimInt + ToString>(&self, value: T) -> String {
+    format!("{}{}{}", self.prefix, self.format(value), self.suffix)
+  }
+
+  /// Generates the counter representation of `value`, following the fallback
+  /// when needed.
+  fn generate(&self, value: i128) -> String {
+    let in_range = match &self.range {
+      Some(ranges) => ranges.iter().any(|&range| contains(range, value)),
+      None => contains(self.system.auto_range(), value),
+    };
+
+    let negative = value < 0 && self.system.uses_negative_sign();
+    let initial = if negative {
+      value.checked_abs().and_then(|value| self.initial(value))
+    } else {
+      self.initial(value)
+    };
+
+    let representation = match initial {
+      Some(representation) if in_range => representation,
+      _ => return self.fallback_style().generate(value),
+    };
+
+    // Pad the representation to the minimum length, counting the negative
+    // sign towards it.
+    let mut length = representation.chars().count();
+    if negative {
+      length += self.negative.0.chars().count();
+      length += self.negative.1.chars().count();
+    }
+
+    let padding = self.pad.1.repeat(self.pad.0.saturating_sub(length));
+    if negative {
+      format!(
+        "{}{}{}{}",
+        self.negative.0, padding, representation, self.negative.1
+      )
+    } else {
+      padding + &representation
+    }
+  }
+
+  /// Returns the counter style to use for values this one can't represent.
+  fn fallback_style(&self) -> Self {
+    // Decimal can represent every value, so it never needs a fallback itself.
+    Self::predefined(&self.fallback)
+      .filter(|fallback| fallback.name != self.name)
+      .or_else(|| Self::predefined(DECIMAL))
+      .unwrap()
+  }
+
+  /// Generates the initial representation of `value` using the system, or
+  /// returns [`None`] when the system can't represent it.
+  fn initial(&self, value: i128) -> Option<String> {
+    let symbols = &self.symbols;
+    let count = i128::try_from(symbols.len()).ok()?;
+
+    match self.system {
+      System::Cyclic => {
+        // Safe to cast since the index is always smaller than the length.
+        let index = (value - 1).rem_euclid(count) as usize;
+        Some(symbols[index].clone())
+      }
+      System::Fixed(first) => {
+        let index = usize::try_from(value.checked_sub(first)?).ok()?;
+        symbols.get(index).cloned()
+      }
+      System::Symbolic => {
+        if value < 1 {
+          return None;
+        }
+
+        let repetitions = usize::try_from((value - 1) / count + 1).ok()?;
+        if repetitions > LENGTH_LIMIT {
+          return None;
+        }
+
+        Some(symbols[((value - 1) % count) as usize].repeat(repetitions))
+      }
+      System::Alphabetic => {
+        if value < 1 {
+          return None;
+        }
+
+        let mut digits = Vec::new();
+        let mut remaining = value;
+        while remaining != 0 {
+          remaining -= 1;
+          digits.push(&symbols[(remaining % count) as usize]);
+          remaining /= count;
+        }
+
+        Some(digits.into_iter().rev().map(String::as_str).collect())
+      }
+      System::Numeric => {
+        if value == 0 {
+          return Some(symbols[0].clone());
+        }
+
+        let mut digits = Vec::new();
+        let mut remaining = value;
+        while remaining != 0 {
+          digits.push(&symbols[(remaining % count) as usize]);
+          remaining /= count;
+        }
+
+        Some(digits.into_iter().rev().map(String::as_str).collect())
+      }
+      System::Additive => self.initial_additive(u128::try_from(value).ok()?),
+    }
+  }
+
+  /// Generates the initial representation of `value` using the additive
+  /// system, or returns [`None`] when the weights can't add up to it.
+  fn initial_additive(&self, value: u128) -> Option<String> {
+    if value == 0 {
+      return self
+        .additive_symbols
+        .iter()
+        .find(|(weight, _)| *weight == 0)
+        .map(|(_, symbol)| symbol.clone());
+    }
+
+    let mut result = String::new();
+    let mut remaining = value;
+    let mut length = 0;
+
+    for (weight, symbol) in &self.additive_symbols {
+      if *weight == 0 || *weight > remaining {
+        continue;
+      }
+
+      let repetitions = usize::try_from(remaining / weight).ok()?;
+      length += repetitions;
+      if length > LENGTH_LIMIT {
+        return None;
+      }
+
+      result += &symbol.repeat(repetitions);
+      remaining %= weight;
+      if remaining == 0 {
+        return Some(result);
+      }
+    }
+
+    None
+  }
+
+  /// Sets the `descriptor` to its parsed `value`.
+  fn set_descriptor(
+    &mut self,
+    descriptor: &str,
+    value: Vec<Token>,
+  ) -> Result<(), CounterStyleError> {
+    let invalid = || CounterStyleError::InvalidDescriptor(descriptor.into());
+
+    match descriptor {
+      "system" => {
+        self.system = match value.as_slice() {
+          [Token::Ident(system)] => match system.as_str() {
+            "cyclic" => System::Cyclic,
+            "fixed" => System::Fixed(1),
+            "symbolic" => System::Symbolic,
+            "alphabetic" => System::Alphabetic,
+            "numeric" => System::Numeric,
+            "additive" => System::Additive,
+            _ => return Err(invalid()),
+          },
+          [Token::Ident(system), Token::Number(first)] if system == "fixed" => {
+            System::Fixed(*first)
+          }
+          _ => return Err(invalid()),
+        };
+      }
+      "symbols" => {
+        self.symbols = value
+          .into_iter()
+          .map(|token| token.into_symbol().ok_or_else(invalid))
+          .collect::<Result<_, _>>()?;
+      }
+      "additive-symbols" => {
+        let mut additive_symbols = Vec::new();
+        for tuple in value.split(|token| *token == Token::Comma) {
+          let (weight, symbol) = match tuple {
+            [Token::Number(weight), symbol]
+            | [symbol, Token::Number(weight)] => (*weight, symbol.clone()),
+            _ => return Err(invalid()),
+          };
+
+          let weight = u128::try_from(weight).map_err(|_| invalid())?;
+          let symbol = symbol.into_symbol().ok_or_else(invalid)?;
+          additive_symbols.push((weight, symbol));
+        }
+
+        // The weights have to be in strictly descending order.
+        if additive_symbols
+          .windows(2)
+          .any(|pair| pair[0].0 <= pair[1].0)
+        {
+          return Err(invalid());
+        }
+
+        self.additive_symbols = additive_symbols;
+      }
+      "negative" => {
+        let mut symbols = value.into_iter().map(Token::into_symbol);
+        self.negative = match (symbols.next(), symbols.next(), symbols.next()) {
+          (Some(Some(before)), None, None) => (before, String::new()),
+          (Some(Some(before)), Some(Some(after)), None) => (before, after),
+          _ => return Err(invalid()),
+        };
+      }
+      "prefix" | "suffix" => {
+        let symbol = match <[Token; 1]>::try_from(value) {
+          Ok([token]) => token.into_symbol().ok_or_else(invalid)?,
+          Err(_) => return Err(invalid()),
+        };
+
+        if descriptor == "prefix" {
+          self.prefix = symbol;
+        } else {
+          self.suffix = symbol;
+        }
+      }
+      "range" => {
+        if let [Token::Ident(auto)] = value.as_slice() {
+          if auto != "auto" {
+            return Err(invalid());
+          }
+
+          self.range = None;
+          return Ok(());
+        }
+
+        let bound = |token: &Token| match token {
+          Token::Number(bound) => Ok(Some(*bound)),
+          Token::Ident(infinite) if infinite == "infinite" => Ok(None),
+          _ => Err(invalid()),
+        };
+
+        let mut ranges = Vec::new();
+        for range in value.split(|token| *token == Token::Comma) {
+          let [lower, upper] = range else {
+            return Err(invalid());
+          };
+
+          let (lower, upper) = (bound(lower)?, bound(upper)?);
+          if let (Some(lower), Some(upper)) = (lower, upper) {
+            if lower > upper {
+              return Err(invalid());
+            }
+          }
+
+          ranges.push((lower, upper));
+        }
+
+        self.range = Some(ranges);
+      }
+      "pad" => {
+        let (length, symbol) = match <[Token; 2]>::try_from(value) {
+          Ok([Token::Number(length), symbol])
+          | Ok([symbol, Token::Number(length)]) => (length, symbol),
+          _ => return Err(invalid()),
+        };
+
+        let length = usize::try_from(length).map_err(|_| invalid())?;
+        self.pad = (length, symbol.into_symbol().ok_or_else(invalid)?);
+      }
+      "fallback" => {
+        self.fallback = match value.as_slice() {
+          [Token::Ident(fallback)] => fallback.clone(),
+          _ => return Err(invalid()),
+        };
+      }
+      "speak-as" => (),
+      _ => {
+        return Err(CounterStyleError::UnknownDescriptor(descriptor.into()));
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Checks that the counter style has the symbols its system needs.
+  fn validate(&self) -> Result<(), CounterStyleError> {
+    let valid = match self.system {
+      System::Additive => !self.additive_symbols.is_empty(),
+      System::Alphabetic | System::Numeric => self.symbols.len() >= 2,
+      _ => !self.symbols.is_empty(),
+    };
+
+    if !valid {
+      return Err(CounterStyleError::MissingSymbols(self.name.clone()));
+    }
+
+    Ok(())
+  }
+}
+
+/// Returns whether `value` is within the inclusive `range`, where [`None`] is
+/// an infinite bound.
+fn contains(range: (Option<i128>, Option<i128>), value: i128) -> bool {
+  range.0.map_or(true, |lower| lower <= value)
+    && range.1.map_or(true, |upper| value <= upper)
+}
+
+/// A token of a CSS `@counter-style` rule.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Token {
+  /// An at-keyword without the "@" (ie. "counter-style").
+  AtKeyword(String),
+
+  /// An identifier, with any escapes resolved.
+  Ident(String),
+
+  /// A quoted string, with any escapes resolved.
+  String(String),
+
+  /// An integer.
+  Number(i128),
+
+  /// A ":".
+  Colon,
+
+  /// A ";".
+  Semicolon,
+
+  /// A ",".
+  Comma,
+
+  /// A "{".
+  OpenBrace,
+
+  /// A "}".
+  CloseBrace,
+}
+
+impl Token {
+  /// Returns the symbol of an identifier or string token, which are the only
+  /// kinds of symbols supported.
+  fn into_symbol(self) -> Option<String> {
+    match self {
+      Self::Ident(symbol) | Self::String(symbol) => Some(symbol),
+      _ => None,
+    }
+  }
+}
+
+/// Splits a CSS `@counter-style` rule into [`Token`]s, skipping whitespace and
+/// comments.
+fn tokenize(input: &str) -> Result<Vec<Token>, CounterStyleError> {
+  let mut tokens = Vec::new();
+  let mut characters = input.chars().peekable();
+
+  while let Some(&character) = characters.peek() {
+    let token = match character {
+      _ if character.is_whitespace() => {
+        characters.next();
+        continue;
+      }
+      '/' => {
+        characters.next();
+        if characters.next() != Some('*') {
+          return Err(CounterStyleError::InvalidSyntax);
+        }
+
+        // Skip everything up to and including the end of the comment.
+        let mut previous = None;
+        loop {
+          match characters.next() {
+            Some('/') if previous == Some('*') => break,
+            Some(character) => previous = Some(character),
+            None => return Err(CounterStyleError::InvalidSyntax),
+          }
+        }
+
+        continue;
+      }
+      ':' | ';' | ',' | '{' | '}' => {
+        characters.next();
+        match character {
+          ':' => Token::Colon,
+          ';' => Token::Semicolon,
+          ',' => Token::Comma,
+          '{' => Token::OpenBrace,
+          _ => Token::CloseBrace,
+        }
+      }
+      '@' => {
+        characters.next();
+        Token::AtKeyword(name(&mut characters)?)
+      }
+      '"' | '\'' => {
+        characters.next();
+        let mut string = String::new();
+        loop {
+          match characters.next() {
+            Some(quote) if quote == character => break,
+            Some('\\') => string.push(escape(&mut characters)?),
+            Some(character) => string.push(character),
+            None => return Err(CounterStyleError::InvalidSyntax),
+          }
+        }
+
+        Token::String(string)
+      }
+      '0'..='9' | '+' | '-' => {
+        let mut lookahead = characters.clone();
+        lookahead.next();
+        let signed_number = character.is_ascii_digit()
+          || lookahead.peek().is_some_and(char::is_ascii_digit);
+
+        if signed_number {
+          let mut number = String::new();
+          number.push(character);
+          characters.next();
+          while let Some(digit) = characters.next_if(char::is_ascii_digit) {
+            number.push(digit);
+          }
+
+          let number = number
+            .parse()
+            .map_err(|_| CounterStyleError::InvalidSyntax)?;
+          Token::Number(number)
+        } else {
+          Token::Ident(name(&mut characters)?)
+        }
+      }
+      _ => Token::Ident(name(&mut characters)?),
+    };
+
+    tokens.push(token);
+  }
+
+  Ok(tokens)
+}
+
+/// Consumes a CSS name (the characters of an identifier), with any escapes
+/// resolved.
+fn name(
+  characters: &mut core::iter::Peekable<core::str::Chars>,
+) -> Result<String, CounterStyleError> {
+  let mut name = String::new();
+  while let Some(&character) = characters.peek() {
+    match character {
+      '\\' => {
+        characters.next();
+        name.push(escape(characters)?);
+      }
+      'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => {
+        characters.next();
+        name.push(character);
+      }
+      _ if !character.is_ascii() && !character.is_whitespace() => {
+        characters.next();
+        name.push(character);
+      }
+      _ => break,
+    }
+  }
+
+  if name.is_empty() {
+    return Err(CounterStyleError::InvalidSyntax);
+  }
+
+  Ok(name)
+}
+
+/// Consumes the rest of a CSS escape after the "\", which is either up to 6
+/// hexadecimal digits followed by an optional whitespace or a single
+/// character.
+fn escape(
+  characters: &mut core::iter::Peekable<core::str::Chars>,
+) -> Result<char, CounterStyleError> {
+  let mut hexadecimal = String::new();
+  while hexadecimal.len() < 6 {
+    match characters.next_if(char::is_ascii_hexdigit) {
+      Some(digit) => hexadecimal.push(digit),
+      None => break,
+    }
+  }
+
+  if hexadecimal.is_empty() {
+    return characters.next().ok_or(CounterStyleError::InvalidSyntax);
+  }
+
+  characters.next_if(|character| character.is_whitespace());
+  u32::from_str_radix(&hexadecimal, 16)
+    .ok()
+    .and_then(char::from_u32)
+    .ok_or(CounterStyleError::InvalidSyntax)
+}
@@ -0,0 +1,69 @@
+//! The `upper-roman` and `lower-roman` counter styles from CSS Counter Styles
+//! Level 3.
+
+use alloc::string::{String, ToString};
+
+use crate::Roman;
+
+/// The largest value in the range of the Roman numeral counter styles, which
+/// starts at 1.
+const RANGE_END: u16 = 3999;
+
+/// A counter formatter for the CSS `upper-roman` and `lower-roman` counter
+/// styles, as used by `list-style-type`.
+///
+/// Values from 1 to 3999 are written as Roman numerals and any other value
+/// falls back to the `decimal` counter style, which writes negative values
+/// with the "-" from the `negative` descriptor. The output is the same as the
+/// predefined [`CounterStyle`][crate::CounterStyle]s of the same name.
+///
+/// ## Example
+///
+/// ```rust
+/// use romantic::RomanCounter;
+///
+/// let upper = RomanCounter::upper_roman();
+/// assert_eq!(upper.format(14), "XIV");
+/// assert_eq!(upper.format(4000), "4000");
+/// assert_eq!(upper.format(0), "0");
+/// assert_eq!(upper.format(-3), "-3");
+///
+/// let lower = RomanCounter::lower_roman();
+/// assert_eq!(lower.format(14), "xiv");
+/// ```
+#[derive(Debug)]
+pub struct RomanCounter {
+  /// The [`Roman`] used for values within the range.
+  roman: Roman,
+}
+
+impl RomanCounter {
+  /// Creates the counter formatter for the `upper-roman` counter style.
+  pub fn upper_roman() -> Self {
+    Self {
+      roman: Roman::default(),
+    }
+  }
+
+  /// Creates the counter formatter for the `lower-roman` counter style.
+  pub fn lower_roman() -> Self {
+    Self {
+      roman: Roman::default().with_minuscule(true),
+    }
+  }
+
+  /// Formats a counter `value` the same way browsers render it as a list
+  /// marker, without the suffix.
+  pub fn format<T: num::PrimInt + ToString>(&self, value: T) -> String {
+    let in_range =
+      value >= T::one() && T::from(RANGE_END).map_or(true, |end| value <= end);
+
+    match self.roman.to_string(value) {
+      Ok(result) if in_range => result,
+
+      // The `decimal` fallback writes negative values with the same "-" as
+      // `to_string`.
+      _ => value.to_string(),
+    }
+  }
+}
@@ -0,0 +1,77 @@
+//! The [`ToRoman`] and [`FromRoman`] extension traits for converting integers
+//! and strings directly.
+
+use alloc::string::{String, ToString};
+
+use crate::{default_roman, ConversionError, Roman};
+
+/// Converts integers to Roman numerals, implemented for every
+/// [`num::PrimInt`].
+///
+/// ## Example
+///
+/// ```rust
+/// use romantic::{Roman, ToRoman};
+///
+/// assert_eq!(2022_u32.to_roman().unwrap(), "MMXXII");
+///
+/// let custom = Roman::new(&['A', 'B', 'C']);
+/// assert_eq!(9.to_roman_with(&custom).unwrap(), "AC");
+/// ```
+pub trait ToRoman {
+  /// Converts the integer using [`Roman::default`], which is only created
+  /// once and shared between calls.
+  fn to_roman(self) -> Result<String, ConversionError>;
+
+  /// Converts the integer using `roman`, like [`Roman::to_string`].
+  fn to_roman_with(self, roman: &Roman) -> Result<String, ConversionError>;
+}
+
+impl<T: num::PrimInt + ToString> ToRoman for T {
+  fn to_roman(self) -> Result<String, ConversionError> {
+    default_roman().to_string(self)
+  }
+
+  fn to_roman_with(self, roman: &Roman) -> Result<String, ConversionError> {
+    roman.to_string(self)
+  }
+}
+
+/// Converts Roman numerals to integers, implemented for anything that can be
+/// used as a [`str`] (like [`String`]).
+///
+/// ## Example
+///
+/// ```rust
+/// use romantic::{FromRoman, Roman};
+///
+/// assert_eq!("MMXXII".parse_roman::<u16>().unwrap(), 2022);
+/// assert_eq!(String::from("XIV").parse_roman::<i32>().unwrap(), 14);
+///
+/// let custom = Roman::new(&['A', 'B', 'C']);
+/// assert_eq!("AC".parse_roman_with::<u8>(&custom).unwrap(), 9);
+/// ```
+pub trait FromRoman {
+  /// Converts the numeral using [`Roman::default`], which is only created
+  /// once and shared between calls.
+  fn parse_roman<T: num::PrimInt>(&self) -> Result<T, ConversionError>;
+
+  /// Converts the numeral using `roman`, like [`Roman::from_str`].
+  fn parse_roman_with<T: num::PrimInt>(
+    &self,
+    roman: &Roman,
+  ) -> Result<T, ConversionError>;
+}
+
+impl<S: AsRef<str> + ?Sized> FromRoman for S {
+  fn parse_roman<T: num::PrimInt>(&self) -> Result<T, ConversionError> {
+    default_roman().from_str(self.as_ref())
+  }
+
+  fn parse_roman_with<T: num::PrimInt>(
+    &self,
+    roman: &Roman,
+  ) -> Result<T, ConversionError> {
+    roman.from_str(self.as_ref())
+  }
+}
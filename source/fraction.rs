@@ -0,0 +1,164 @@
+//! Fractions in uncia notation, where a half is written as "S" and each
+//! twelfth (uncia) is written as a dot.
+
+use alloc::string::{String, ToString};
+use num::rational::Ratio;
+
+use crate::{ConversionError, Roman};
+
+/// The number of parts used to sum up fractional characters, since the
+/// smallest supported fraction is the siliqua of 1/1728.
+const PARTS: u128 = 1728;
+
+/// The number of parts in one twelfth (uncia).
+const UNCIA: u128 = PARTS / 12;
+
+/// The character for a half (semis).
+const SEMIS: char = 'S';
+
+/// The characters for 0 through 5 twelfths, written after the semis.
+const UNCIAE: [&str; 6] = ["", "·", ":", "∴", "∷", "⁙"];
+
+/// Returns the number of [`PARTS`] a fractional character is worth.
+fn parts_of_character(character: char) -> Option<u128> {
+  let parts = match character {
+    SEMIS => 6 * UNCIA,
+    '·' | '\u{10191}' => UNCIA,
+    ':' | '\u{10190}' => 2 * UNCIA,
+    '∴' => 3 * UNCIA,
+    '∷' => 4 * UNCIA,
+    '⁙' => 5 * UNCIA,
+    '\u{10192}' => UNCIA / 2,
+    '\u{10193}' => UNCIA / 6,
+    '\u{10194}' => UNCIA / 12,
+    '\u{10195}' => 1,
+    _ => return None,
+  };
+
+  Some(parts)
+}
+
+impl Roman {
+  /// Converts a [`Ratio`] to a [`String`] in uncia notation.
+  ///
+  /// The whole part is converted like [`Roman::to_string`] and the fractional
+  /// part is written in twelfths, with "S" for a half and "·", ":", "∴", "∷"
+  /// and "⁙" for 1 through 5 twelfths. Numbers that aren't a whole number of
+  /// twelfths return an
+  /// [`InvalidFraction`][ConversionError::InvalidFraction] error.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use num::rational::Ratio;
+  /// use romantic::Roman;
+  ///
+  /// let roman = Roman::default();
+  /// assert_eq!(roman.to_string_fraction(Ratio::new(15, 2)).unwrap(), "VIIS");
+  /// assert_eq!(roman.to_string_fraction(Ratio::new(5, 12)).unwrap(), "⁙");
+  /// assert!(roman.to_string_fraction(Ratio::new(1, 5)).is_err());
+  /// ```
+  pub fn to_string_fraction<T: num::PrimInt + num::Integer + ToString>(
+    &self,
+    number: Ratio<T>,
+  ) -> Result<String, ConversionError> {
+    if *number.numer() < T::zero() || *number.denom() < T::zero() {
+      return Err(ConversionError::NegativeNumber);
+    }
+
+    let numerator = number
+      .numer()
+      .to_u128()
+      .ok_or(ConversionError::GenericConversion)?;
+    let denominator = number
+      .denom()
+      .to_u128()
+      .ok_or(ConversionError::GenericConversion)?;
+
+    let twelfths =
+      numerator.checked_mul(12).ok_or(ConversionError::Overflow)?;
+    if twelfths.checked_rem(denominator) != Some(0) {
+      return Err(ConversionError::InvalidFraction);
+    }
+
+    let twelfths = twelfths / denominator;
+    let (whole, twelfths) = (twelfths / 12, (twelfths % 12) as usize);
+
+    let mut result = String::new();
+    if whole > 0 || twelfths == 0 {
+      result += &self.encode(whole)?;
+    }
+
+    // A set that uses "S" as a numeral writes its halves as dots instead.
+    let semis_in_set = self.character_magnitude_map.contains_key(&SEMIS);
+    match twelfths {
+      6.. if semis_in_set => result += &(UNCIAE[5].to_string() + UNCIAE[1]),
+      6.. => result.push(SEMIS),
+      _ => (),
+    }
+
+    result += UNCIAE[twelfths % 6];
+    Ok(result)
+  }
+
+  /// Converts a [`str`] in uncia notation to a [`Ratio`].
+  ///
+  /// Any fractional characters have to come after the whole part. Besides the
+  /// characters written by [`Roman::to_string_fraction`], repeated dots and
+  /// the Unicode Roman fraction signs (U+10190 to U+10195) are also accepted.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use num::rational::Ratio;
+  /// use romantic::Roman;
+  ///
+  /// let roman = Roman::default();
+  /// let half = roman.from_str_fraction::<i32>("VIIS").unwrap();
+  /// assert_eq!(half, Ratio::new(15, 2));
+  ///
+  /// let dots = roman.from_str_fraction::<i32>("∴··").unwrap();
+  /// assert_eq!(dots, Ratio::new(5, 12));
+  ///
+  /// let semuncia = roman.from_str_fraction::<i32>("I\u{10192}").unwrap();
+  /// assert_eq!(semuncia, Ratio::new(25, 24));
+  /// ```
+  pub fn from_str_fraction<T: num::PrimInt + num::Integer>(
+    &self,
+    input: &str,
+  ) -> Result<Ratio<T>, ConversionError> {
+    // A set that uses "S" as a numeral can't also use it for a half.
+    let is_fractional = |character: char| {
+      !self.character_magnitude_map.contains_key(&character)
+        && parts_of_character(character).is_some()
+    };
+
+    let split = input
+      .char_indices()
+      .rev()
+      .take_while(|&(_, character)| is_fractional(character))
+      .last()
+      .map_or(input.len(), |(index, _)| index);
+    let (whole, fraction) = input.split_at(split);
+
+    let parts = fraction
+      .chars()
+      .filter_map(parts_of_character)
+      .try_fold(0_u128, u128::checked_add)
+      .ok_or(ConversionError::Overflow)?;
+
+    let numerator = self
+      .from_str::<u128>(whole)?
+      .checked_mul(PARTS)
+      .and_then(|whole| whole.checked_add(parts))
+      .ok_or(ConversionError::Overflow)?;
+
+    // Reduce first so small types only need to fit the reduced fraction,
+    // not the 1728 parts it's counted in.
+    let ratio = Ratio::new(numerator, PARTS);
+    Ok(Ratio::new_raw(
+      T::from(*ratio.numer()).ok_or(ConversionError::Overflow)?,
+      T::from(*ratio.denom()).ok_or(ConversionError::Overflow)?,
+    ))
+  }
+}
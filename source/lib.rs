@@ -1,3 +1,4 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 #![warn(missing_docs, clippy::missing_docs_in_private_items)]
 
@@ -6,6 +7,7 @@
 //! Using the default Roman numeral system.
 //!
 //! ```rust
+//! # #[cfg(feature = "alloc")] {
 //! use romantic::Roman;
 //!
 //! let roman = Roman::default();
@@ -15,11 +17,13 @@
 //!
 //! // The default Roman numeral system has a maximum of 3999.
 //! assert!(roman.to_string(4000).is_err());
+//! # }
 //! ```
 //!
 //! Using your own custom character set.
 //!
 //! ```rust
+//! # #[cfg(feature = "alloc")] {
 //! use romantic::Roman;
 //!
 //! // The order of characters in the array determines their value.
@@ -33,9 +37,80 @@
 //! // (the equivalent of VIII). To increase the maximum range, use
 //! // more characters.
 //! assert!(custom.to_string(9).is_err());
+//! # }
 //! ```
+//!
+//! ## Features
+//!
+//! - `std` (default): implements writing to [`std::io::Write`] outputs and
+//!   enables `alloc`.
+//! - `alloc`: everything built around [`Roman`], which needs allocations.
+//!   The shared default system used by [`ToRoman`] and [`RomanNumeral`]
+//!   needs compare-and-swap atomics.
+//! - `portable-atomic`: enables `alloc` on targets without compare-and-swap
+//!   atomics (like `thumbv6m-none-eabi`) through the `portable-atomic` crate,
+//!   which then needs its `critical-section` or `unsafe-assume-single-core`
+//!   option.
+//!
+//! Without either, the crate is `no_std` and can still write numbers in the
+//! default Roman numeral system with [`write_default`] and
+//! [`encode_default`].
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
-use std::collections::HashMap;
+#[cfg(feature = "alloc")]
+use alloc::{
+  collections::BTreeMap,
+  string::{String, ToString},
+  vec::Vec,
+};
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+mod builder;
+#[cfg(feature = "alloc")]
+mod bytes;
+#[cfg(feature = "alloc")]
+mod counter_style;
+#[cfg(feature = "alloc")]
+mod css;
+#[cfg(feature = "alloc")]
+mod extension;
+#[cfg(feature = "alloc")]
+mod fraction;
+#[cfg(feature = "alloc")]
+mod numeral;
+#[cfg(feature = "alloc")]
+mod permissive;
+#[cfg(feature = "alloc")]
+mod spreadsheet;
+mod standard;
+#[cfg(feature = "alloc")]
+mod tables;
+#[cfg(feature = "alloc")]
+mod writer;
+
+#[cfg(feature = "alloc")]
+pub use builder::RomanBuilder;
+#[cfg(feature = "alloc")]
+pub use counter_style::CounterStyle;
+#[cfg(feature = "alloc")]
+pub use css::RomanCounter;
+#[cfg(feature = "alloc")]
+pub use extension::{FromRoman, ToRoman};
+#[cfg(feature = "alloc")]
+pub use numeral::RomanNumeral;
+#[cfg(feature = "alloc")]
+pub use permissive::Irregularity;
+pub use standard::{encode_default, write_default};
+#[cfg(feature = "alloc")]
+pub use writer::RomanDisplay;
+
+#[cfg(feature = "alloc")]
+use tables::Tables;
+#[cfg(feature = "alloc")]
+use writer::{CharCounter, Overline, Styled};
 
 /// All possible errors that can occur during conversion.
 #[derive(Debug, thiserror::Error)]
@@ -49,36 +124,383 @@ pub enum ConversionError {
   #[error("Invalid character \"{0}\" encountered")]
   InvalidCharacter(char),
 
+  /// The error when an input byte passed to [`Roman::from_bytes`] isn't
+  /// part of a character in the [`Roman`] set, with the offset of the byte.
+  #[error("Invalid byte at offset {0}")]
+  InvalidByte(usize),
+
   /// The error when an input magnitude does not have an associated character in
   /// the [`Roman`] set.
   #[error("Missing magnitude \"{0}\" for input number")]
   MissingMagnitude(usize),
 
+  /// The error when a strictly parsed input repeats a character more often
+  /// than the canonical form allows (ie. "IIII" or "VV").
+  #[error("Invalid repetition of character \"{0}\"")]
+  InvalidRepetition(char),
+
+  /// The error when a strictly parsed input contains a subtractive pair that
+  /// the canonical form does not allow (ie. "IC" or "VX").
+  #[error("Invalid subtraction of \"{0}\" from \"{1}\"")]
+  InvalidSubtraction(char, char),
+
+  /// The error when a strictly parsed input has a character that is out of
+  /// order for the canonical form (ie. "IIV" or "IXI").
+  #[error("Character \"{0}\" is out of order")]
+  InvalidOrder(char),
+
+  /// The error when part of the input number can't be written with the
+  /// explicit values of the [`Roman`] set (ie. 16 without 'I').
+  #[error("Value {0} cannot be written with the character set")]
+  UnreachableValue(u128),
+
+  /// The error when [`OverflowPolicy::Repeat`] would repeat the largest
+  /// character more often than the limit set with
+  /// [`Roman::with_repeat_limit`].
+  #[error("Repeating the largest character exceeds the limit of {0}")]
+  RepeatLimit(usize),
+
+  /// The error when a spreadsheet form isn't between 0 and 4.
+  #[error("Invalid spreadsheet form {0}")]
+  InvalidForm(u8),
+
+  /// The error when a fraction is not a whole number of twelfths.
+  #[error("Fraction is not a whole number of twelfths")]
+  InvalidFraction,
+
   /// The error when an input number is negative.
   #[error("Input number cannot be negative")]
   NegativeNumber,
 
+  /// The error when an input has a negative sign with nothing after it.
+  #[error("Negative sign is not followed by a numeral")]
+  MissingNumeral,
+
+  /// The error when a [`RomanNumeral`] would be zero, which can only be
+  /// written with [`Roman::with_nulla`].
+  #[error("Zero cannot be a numeral without nulla")]
+  ZeroNumeral,
+
   /// The error when calculating an integer would cause an overflow.
   #[error("Operation would cause overflow")]
   Overflow,
+
+  /// The error when [`Roman::write_to`] fails to write to its output.
+  #[error("Failed to write the numeral")]
+  Format(#[from] fmt::Error),
+
+  /// The error when [`Roman::write_to_io`] fails to write to its output.
+  #[cfg(feature = "std")]
+  #[error("Failed to write the numeral: {0}")]
+  Io(#[from] std::io::Error),
+
+  /// The error when [`encode_default`] is given a buffer that's too small,
+  /// with the number of bytes the numeral needs.
+  #[error("Buffer is too small, the numeral needs {0} bytes")]
+  BufferTooSmall(usize),
+}
+
+/// All possible errors that can occur when creating a [`Roman`].
+#[derive(Debug, thiserror::Error)]
+pub enum ConstructionError {
+  /// The error when a character is used for more than one magnitude.
+  #[error("Character \"{0}\" is used for more than one magnitude")]
+  DuplicateCharacter(char),
+
+  /// The error when the character set has no characters.
+  #[error("Character set cannot be empty")]
+  EmptyCharacterSet,
+
+  /// The error when a character's magnitude doesn't fit in a [`usize`].
+  #[error("Magnitude of character \"{0}\" would overflow")]
+  MagnitudeOverflow(char),
+
+  /// The error when a rule refers to a character that isn't in the set.
+  #[error("Character \"{0}\" is not in the character set")]
+  UnknownCharacter(char),
+
+  /// The error when a subtractive pair isn't a unit followed by the next 5 or
+  /// 10 of it (or the next step of a custom pattern).
+  #[error("Character \"{0}\" cannot be subtracted from \"{1}\"")]
+  InvalidSubtractivePair(char, char),
+
+  /// The error when the radix is smaller than 2.
+  #[error("Radix {0} must be at least 2")]
+  InvalidRadix(usize),
+
+  /// The error when a pattern doesn't start with 1 or its values don't
+  /// increase up to the radix.
+  #[error("Pattern must start with 1 and increase up to radix {0}")]
+  InvalidPattern(usize),
+
+  /// The error when a character is given a value of zero.
+  #[error("Character \"{0}\" must have a value greater than zero")]
+  ZeroValue(char),
+
+  /// The error when a character is given the same value as another one.
+  #[error("Character \"{0}\" has the same value as another character")]
+  DuplicateValue(char),
+}
+
+/// All possible errors that can occur when parsing a [`CounterStyle`].
+#[cfg(feature = "alloc")]
+#[derive(Debug, thiserror::Error)]
+pub enum CounterStyleError {
+  /// The error when the input isn't a well-formed `@counter-style` rule.
+  #[error("Invalid @counter-style syntax")]
+  InvalidSyntax,
+
+  /// The error when a descriptor isn't part of CSS Counter Styles Level 3.
+  #[error("Unknown descriptor \"{0}\"")]
+  UnknownDescriptor(String),
+
+  /// The error when a descriptor has an invalid or unsupported value.
+  #[error("Invalid value for descriptor \"{0}\"")]
+  InvalidDescriptor(String),
+
+  /// The error when a counter style doesn't have enough symbols for its
+  /// system.
+  #[error("Counter style \"{0}\" is missing symbols for its system")]
+  MissingSymbols(String),
+}
+
+/// The notation a [`Roman`] uses when converting numbers.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Notation {
+  /// The modern notation using subtractive pairs, ie. 4 = "IV" and 9 = "IX".
+  #[default]
+  Subtractive,
+
+  /// The additive notation found on clock faces and inscriptions that never
+  /// uses subtractive pairs, ie. 4 = "IIII" and 9 = "VIIII".
+  Additive,
+}
+
+/// What [`Roman::to_string`] does with a number that's too large for the
+/// character set.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum OverflowPolicy {
+  /// Return the error, ie.
+  /// [`MissingMagnitude`][ConversionError::MissingMagnitude].
+  #[default]
+  Error,
+
+  /// Write the number in decimal digits instead (ie. "5000").
+  Decimal,
+
+  /// Write the largest number the character set can write instead (ie.
+  /// "MMMCMXCIX").
+  Clamp,
+
+  /// Repeat the character with the largest value as often as needed (ie.
+  /// "MMMMM"), up to the limit set with [`Roman::with_repeat_limit`].
+  Repeat,
+
+  /// Write the thousands with a vinculum over them, like
+  /// [`Roman::with_vinculum`] (ie. "V̅").
+  Vinculum,
+}
+
+/// Returns whether `error` means a number is too large for the character set,
+/// in which case the [`OverflowPolicy`] applies.
+#[cfg(feature = "alloc")]
+fn is_overflow_error(error: &ConversionError) -> bool {
+  matches!(
+    error,
+    ConversionError::MissingMagnitude(_)
+      | ConversionError::UnreachableValue(_)
+      | ConversionError::Overflow
+  )
+}
+
+/// Returns the [`Roman::default`] system, which is only created once.
+#[cfg(feature = "alloc")]
+fn default_roman() -> &'static Roman {
+  static DEFAULT: once_cell::race::OnceBox<Roman> =
+    once_cell::race::OnceBox::new();
+  DEFAULT.get_or_init(|| {
+    alloc::boxed::Box::new(Roman::default().with_lookup_table(true))
+  })
+}
+
+/// The default number of times [`OverflowPolicy::Repeat`] can repeat the
+/// largest character.
+#[cfg(feature = "alloc")]
+const DEFAULT_REPEAT_LIMIT: usize = 10_000;
+
+/// The characters of the default Roman numeral system.
+#[cfg(feature = "alloc")]
+const DEFAULT_CHARACTER_SET: [char; 7] = ['I', 'V', 'X', 'L', 'C', 'D', 'M'];
+
+/// The radix of the default Roman numeral system.
+#[cfg(feature = "alloc")]
+const DEFAULT_RADIX: usize = 10;
+
+/// The values of the characters within each power of [`DEFAULT_RADIX`] in the
+/// default Roman numeral system (ie. 'I' and 'V').
+#[cfg(feature = "alloc")]
+const DEFAULT_PATTERN: [usize; 2] = [1, 5];
+
+/// The combining overline used to write a vinculum, multiplying the character
+/// below it by 1000.
+#[cfg(feature = "alloc")]
+const VINCULUM: char = '\u{0305}';
+
+/// The reversed C (U+2183) used to write numbers in apostrophus notation.
+#[cfg(feature = "alloc")]
+const APOSTROPHUS: char = '\u{2183}';
+
+/// The character used to write zero (nulla), as found in medieval tables.
+#[cfg(feature = "alloc")]
+const NULLA: char = 'N';
+
+/// The Unicode Roman numeral characters (U+2160 to U+2188) and their values,
+/// sorted by code point so uppercase characters come before lowercase ones.
+#[cfg(feature = "alloc")]
+const UNICODE_NUMERALS: &[(char, usize)] = &[
+  ('Ⅰ', 1),
+  ('Ⅱ', 2),
+  ('Ⅲ', 3),
+  ('Ⅳ', 4),
+  ('Ⅴ', 5),
+  ('Ⅵ', 6),
+  ('Ⅶ', 7),
+  ('Ⅷ', 8),
+  ('Ⅸ', 9),
+  ('Ⅹ', 10),
+  ('Ⅺ', 11),
+  ('Ⅻ', 12),
+  ('Ⅼ', 50),
+  ('Ⅽ', 100),
+  ('Ⅾ', 500),
+  ('Ⅿ', 1000),
+  ('ⅰ', 1),
+  ('ⅱ', 2),
+  ('ⅲ', 3),
+  ('ⅳ', 4),
+  ('ⅴ', 5),
+  ('ⅵ', 6),
+  ('ⅶ', 7),
+  ('ⅷ', 8),
+  ('ⅸ', 9),
+  ('ⅹ', 10),
+  ('ⅺ', 11),
+  ('ⅻ', 12),
+  ('ⅼ', 50),
+  ('ⅽ', 100),
+  ('ⅾ', 500),
+  ('ⅿ', 1000),
+  ('ↀ', 1000),
+  ('ↁ', 5000),
+  ('ↂ', 10000),
+  ('ↅ', 6),
+  ('ↆ', 50),
+  ('ↇ', 50000),
+  ('ↈ', 100000),
+];
+
+/// A single numeral from an input string, with any modifiers like a vinculum
+/// already applied to its value.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Symbol {
+  /// The character of the numeral as it appeared in the input.
+  character: char,
+
+  /// The value of the numeral after applying modifiers.
+  value: usize,
+
+  /// Whether the numeral is a Unicode ligature of multiple numerals (ie. Ⅻ),
+  /// which can never be subtracted.
+  ligature: bool,
 }
 
 /// The main struct for [`romantic`][crate].
+#[cfg(feature = "alloc")]
 #[derive(Debug)]
 pub struct Roman {
   /// The mapping of a character to its corresponding magnitude (ie. 1 = 'I').
-  character_magnitude_map: HashMap<char, usize>,
+  character_magnitude_map: BTreeMap<char, usize>,
 
   /// The mapping of a magnitude to its corresponding character (ie. 'I' = 1).
-  magnitude_character_map: HashMap<usize, char>,
+  magnitude_character_map: BTreeMap<usize, char>,
+
+  /// The notation to use for encoding and strict decoding.
+  notation: Notation,
+
+  /// Whether to use vinculums for numbers too large for the character set.
+  vinculum: bool,
+
+  /// Whether to use apostrophus forms for magnitudes of 500 and above.
+  apostrophus: bool,
+
+  /// Whether to accept the Unicode Roman numeral characters as input.
+  unicode_input: bool,
+
+  /// Whether to write the Unicode Roman numeral characters as output.
+  unicode_output: bool,
+
+  /// Whether to write zero as "N" instead of an empty string.
+  nulla: bool,
+
+  /// The prefix for negative numbers, or [`None`] if they aren't allowed.
+  negative_sign: Option<String>,
+
+  /// Whether to write lowercase characters and accept them as input.
+  minuscule: bool,
+
+  /// Whether to write a final "i" as "j" and accept it as input.
+  terminal_j: bool,
+
+  /// Whether subtractive pairs are allowed at all.
+  subtraction: bool,
+
+  /// The magnitudes that can be subtracted from one another, or [`None`] to
+  /// subtract units from the next 5 and 10 of them.
+  subtractive_pairs: Option<Vec<(usize, usize)>>,
+
+  /// The number of times a repeatable character can appear in a row, or
+  /// [`None`] to use the default for the [`Notation`].
+  repetition_limit: Option<usize>,
+
+  /// The magnitudes that can be repeated, or [`None`] to repeat the units.
+  repeatable: Option<Vec<usize>>,
+
+  /// The number each group of characters is multiplied by.
+  radix: usize,
+
+  /// The values of the characters within each group, starting with the unit.
+  pattern: Vec<usize>,
+
+  /// Whether the values were given explicitly instead of following the radix
+  /// and pattern, in which case numbers are written greedily.
+  explicit_values: bool,
+
+  /// What to do with numbers that are too large for the character set.
+  overflow_policy: OverflowPolicy,
+
+  /// The number of times [`OverflowPolicy::Repeat`] can repeat the largest
+  /// character, or [`None`] for no limit.
+  repeat_limit: Option<usize>,
+
+  /// Whether to precompute the numerals for 1 through 3999.
+  lookup_table: bool,
+
+  /// Whether to precompute the digit and ASCII tables.
+  tables_enabled: bool,
+
+  /// The tables precomputed from the rules above.
+  tables: Tables,
 }
 
+#[cfg(feature = "alloc")]
 impl Default for Roman {
   fn default() -> Self {
-    Self::new(&['I', 'V', 'X', 'L', 'C', 'D', 'M'])
+    Self::new(&DEFAULT_CHARACTER_SET)
   }
 }
 
+#[cfg(feature = "alloc")]
 impl Roman {
   /// Creates a new [`Roman`] using the characters in `character_set`.
   ///
@@ -110,156 +532,1718 @@ impl Roman {
   /// assert_eq!(custom.from_str::<i32>("AC").unwrap(), 9);
   /// ```
   pub fn new(character_set: &[char]) -> Self {
-    let mut character_magnitude_map = HashMap::new();
-    let mut magnitude_character_map = HashMap::new();
+    let mut character_magnitude_map = BTreeMap::new();
+    let mut magnitude_character_map = BTreeMap::new();
+
+    // Characters whose magnitude doesn't fit in a `usize` are left out, use
+    // `Roman::try_new` to get an error for them instead.
+    let magnitudes =
+      Self::magnitudes(character_set, DEFAULT_RADIX, &DEFAULT_PATTERN);
+    for (character, value) in magnitudes {
+      if let Some(value) = value {
+        character_magnitude_map.insert(character, value);
+        magnitude_character_map.insert(value, character);
+      }
+    }
+
+    let mut roman =
+      Self::from_maps(character_magnitude_map, magnitude_character_map);
+    roman.update_tables();
+    roman
+  }
 
-    let values = [1, 5];
-    let modulo = values.len();
+  /// Creates a new [`Roman`] using the characters in `character_set`, where
+  /// each group of characters follows the values in `pattern` and each group
+  /// is `radix` times the one before it.
+  fn try_new_with_radix(
+    character_set: &[char],
+    radix: usize,
+    pattern: &[usize],
+  ) -> Result<Self, ConstructionError> {
+    Self::validate_radix(radix, pattern)?;
+    if character_set.is_empty() {
+      return Err(ConstructionError::EmptyCharacterSet);
+    }
 
-    let mut magnitude = 1;
+    let mut character_magnitude_map = BTreeMap::new();
+    let mut magnitude_character_map = BTreeMap::new();
 
-    for (index, &character) in character_set.iter().enumerate() {
-      if index > 0 && index % modulo == 0 {
-        magnitude *= 10;
+    for (character, value) in Self::magnitudes(character_set, radix, pattern) {
+      let value =
+        value.ok_or(ConstructionError::MagnitudeOverflow(character))?;
+      if character_magnitude_map.insert(character, value).is_some() {
+        return Err(ConstructionError::DuplicateCharacter(character));
       }
 
-      let value = magnitude * values[index % modulo];
-      character_magnitude_map.insert(character, value);
       magnitude_character_map.insert(value, character);
     }
 
+    let mut roman =
+      Self::from_maps(character_magnitude_map, magnitude_character_map);
+    roman.radix = radix;
+    roman.pattern = pattern.to_vec();
+    roman.update_tables();
+    Ok(roman)
+  }
+
+  /// Creates a new [`Roman`] using the characters in `character_set` like
+  /// [`Roman::new`], but validates the `character_set` first.
+  ///
+  /// An empty `character_set`, a character that appears more than once or a
+  /// character set so long that its magnitudes don't fit in a [`usize`] will
+  /// return a [`ConstructionError`].
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::{ConstructionError, Roman};
+  ///
+  /// let custom = Roman::try_new(&['A', 'B', 'C']).unwrap();
+  /// assert_eq!(custom.to_string(9).unwrap(), "AC");
+  ///
+  /// assert!(matches!(
+  ///   Roman::try_new(&['A', 'B', 'A']),
+  ///   Err(ConstructionError::DuplicateCharacter('A'))
+  /// ));
+  /// assert!(matches!(
+  ///   Roman::try_new(&[]),
+  ///   Err(ConstructionError::EmptyCharacterSet)
+  /// ));
+  /// ```
+  pub fn try_new(character_set: &[char]) -> Result<Self, ConstructionError> {
+    Self::try_new_with_radix(character_set, DEFAULT_RADIX, &DEFAULT_PATTERN)
+  }
+
+  /// Returns an error when `radix` and `pattern` can't be used to determine
+  /// the magnitudes of a character set.
+  fn validate_radix(
+    radix: usize,
+    pattern: &[usize],
+  ) -> Result<(), ConstructionError> {
+    if radix < 2 {
+      return Err(ConstructionError::InvalidRadix(radix));
+    }
+
+    // The pattern has to start with the unit and every value in it has to be
+    // smaller than the next one, with the last one smaller than the radix.
+    let increasing = pattern.windows(2).all(|pair| pair[0] < pair[1]);
+    if pattern.first() != Some(&1)
+      || !increasing
+      || pattern.last().is_some_and(|&last| last >= radix)
+    {
+      return Err(ConstructionError::InvalidPattern(radix));
+    }
+
+    Ok(())
+  }
+
+  /// Returns each character in `character_set` with its magnitude, or
+  /// [`None`] when the magnitude doesn't fit in a [`usize`].
+  fn magnitudes<'a>(
+    character_set: &'a [char],
+    radix: usize,
+    pattern: &'a [usize],
+  ) -> impl Iterator<Item = (char, Option<usize>)> + 'a {
+    let modulo = pattern.len();
+
+    let mut magnitude = Some(1_usize);
+
+    character_set
+      .iter()
+      .enumerate()
+      .map(move |(index, &character)| {
+        if index > 0 && index % modulo == 0 {
+          magnitude = magnitude.and_then(|m| m.checked_mul(radix));
+        }
+
+        let value =
+          magnitude.and_then(|m| m.checked_mul(pattern[index % modulo]));
+        (character, value)
+      })
+  }
+
+  /// Creates a new [`Roman`] from its character maps with all the settings at
+  /// their defaults, without building its [`Tables`].
+  fn from_maps(
+    character_magnitude_map: BTreeMap<char, usize>,
+    magnitude_character_map: BTreeMap<usize, char>,
+  ) -> Self {
     Self {
       character_magnitude_map,
       magnitude_character_map,
+      notation: Notation::default(),
+      vinculum: false,
+      apostrophus: false,
+      unicode_input: false,
+      unicode_output: false,
+      nulla: false,
+      negative_sign: None,
+      minuscule: false,
+      terminal_j: false,
+      subtraction: true,
+      subtractive_pairs: None,
+      repetition_limit: None,
+      repeatable: None,
+      radix: DEFAULT_RADIX,
+      pattern: DEFAULT_PATTERN.to_vec(),
+      explicit_values: false,
+      overflow_policy: OverflowPolicy::default(),
+      repeat_limit: Some(DEFAULT_REPEAT_LIMIT),
+      lookup_table: false,
+      tables_enabled: true,
+      tables: Tables::default(),
     }
   }
 
-  /// Converts a [`str`] to a generic integer [`num::PrimInt`].
+  /// Creates a new [`Roman`] using the primary characters in `character_set`,
+  /// each with any number of aliases.
+  ///
+  /// The primary characters determine their magnitude the same way as in
+  /// [`Roman::new`] and are always used by [`Roman::to_string`], while
+  /// [`Roman::from_str`] accepts both the primary characters and their
+  /// aliases. The primary characters are validated like in [`Roman::try_new`]
+  /// and an alias used for more than one magnitude returns a
+  /// [`DuplicateCharacter`][ConstructionError::DuplicateCharacter] error.
   ///
   /// ## Example
   ///
   /// ```rust
   /// use romantic::Roman;
   ///
-  /// let roman = Roman::default();
-  /// assert_eq!(roman.from_str::<i32>("IX").unwrap(), 9);
+  /// let roman = Roman::new_with_aliases(&[
+  ///   ('I', &['i', 'Ⅰ', 'Ｉ']),
+  ///   ('V', &['v', 'Ⅴ', 'Ｖ']),
+  ///   ('X', &['x', 'Ⅹ', 'Ｘ']),
+  /// ])
+  /// .unwrap();
   ///
-  /// let custom = Roman::new(&['A', 'B', 'C']);
-  /// assert_eq!(custom.from_str::<i32>("AC").unwrap(), 9);
+  /// assert_eq!(roman.to_string(14).unwrap(), "XIV");
+  /// assert_eq!(roman.from_str::<i32>("xⅠＶ").unwrap(), 14);
+  ///
+  /// assert!(Roman::new_with_aliases(&[('I', &['V']), ('V', &[])]).is_err());
   /// ```
-  pub fn from_str<T: num::PrimInt>(
-    &self,
-    input: &str,
-  ) -> Result<T, ConversionError> {
-    let mut characters = input.chars().peekable();
-    let mut result = T::zero();
-
-    while let Some(character) = characters.next() {
-      let value = self
-        .character_magnitude_map
-        .get(&character)
-        .ok_or(ConversionError::InvalidCharacter(character))?;
-
-      let generic_value =
-        T::from(*value).ok_or(ConversionError::GenericConversion)?;
+  pub fn new_with_aliases(
+    character_set: &[(char, &[char])],
+  ) -> Result<Self, ConstructionError> {
+    let primaries = character_set
+      .iter()
+      .map(|&(primary, _)| primary)
+      .collect::<Vec<_>>();
+    let mut roman = Self::try_new(&primaries)?;
 
-      if let Some(next) = characters.peek() {
-        let next = self.character_magnitude_map.get(next);
+    for &(primary, aliases) in character_set {
+      let value = roman.character_magnitude_map[&primary];
 
-        let subtract = match next {
-          Some(&next_value) => {
-            (value * 5 == next_value) || (value * 10 == next_value)
+      for &alias in aliases {
+        match roman.character_magnitude_map.insert(alias, value) {
+          Some(existing) if existing != value => {
+            return Err(ConstructionError::DuplicateCharacter(alias));
           }
-          None => false,
-        };
-
-        if subtract {
-          result = result
-            .checked_sub(&generic_value)
-            .ok_or(ConversionError::Overflow)?;
-          continue;
+          _ => (),
         }
       }
-
-      result = result
-        .checked_add(&generic_value)
-        .ok_or(ConversionError::Overflow)?;
     }
 
-    Ok(result)
+    roman.update_tables();
+    Ok(roman)
   }
 
-  /// Converts a generic integer [`num::PrimInt`] to a [`String`].
+  /// Creates a new [`Roman`] using explicit `(character, value)` pairs instead
+  /// of deriving the values from the order of the characters.
+  ///
+  /// The values don't have to follow any pattern, so there can be gaps or
+  /// irregular values. Numbers are written greedily from the largest value
+  /// down, subtracting units from the next 5 and 10 of them where both are
+  /// in the set. A value that would need more repetitions than allowed is
+  /// repeated as often as it can be, leaving the rest to the smaller values.
+  /// A number with a remainder that can't be written this way returns an
+  /// [`UnreachableValue`][ConversionError::UnreachableValue] error naming the
+  /// remainder.
+  ///
+  /// An empty set, a character or value that appears more than once, or a
+  /// value of zero will return a [`ConstructionError`].
   ///
   /// ## Example
   ///
   /// ```rust
-  /// use romantic::Roman;
+  /// use romantic::{ConversionError, Roman};
   ///
-  /// let roman = Roman::default();
-  /// assert_eq!(roman.to_string(9).unwrap(), "IX");
+  /// let roman = Roman::new_with_values(&[
+  ///   ('I', 1),
+  ///   ('V', 5),
+  ///   ('X', 10),
+  ///   ('L', 50),
+  ///   ('C', 100),
+  ///   ('ↁ', 5000),
+  /// ])
+  /// .unwrap();
   ///
-  /// let custom = Roman::new(&['A', 'B', 'C']);
-  /// assert_eq!(custom.to_string(9).unwrap(), "AC");
+  /// assert_eq!(roman.to_string(5149).unwrap(), "ↁCXLIX");
+  /// assert_eq!(roman.from_str::<i32>("ↁCXLIX").unwrap(), 5149);
+  ///
+  /// let roman = Roman::new_with_values(&[('V', 5), ('X', 10)]).unwrap();
+  /// assert!(matches!(
+  ///   roman.to_string(16),
+  ///   Err(ConversionError::UnreachableValue(1))
+  /// ));
   /// ```
-  pub fn to_string<T: num::PrimInt + ToString>(
-    &self,
-    number: T,
-  ) -> Result<String, ConversionError> {
-    if number < T::zero() {
-      return Err(ConversionError::NegativeNumber);
+  pub fn new_with_values(
+    character_set: &[(char, usize)],
+  ) -> Result<Self, ConstructionError> {
+    if character_set.is_empty() {
+      return Err(ConstructionError::EmptyCharacterSet);
     }
 
-    let mut result = String::new();
+    let mut character_magnitude_map = BTreeMap::new();
+    let mut magnitude_character_map = BTreeMap::new();
 
-    for (index, digit) in number.to_string().chars().rev().enumerate() {
-      // Skip any zeroes in the number since we don't have to do anything for it.
-      if digit == '0' {
-        continue;
+    for &(character, value) in character_set {
+      if value == 0 {
+        return Err(ConstructionError::ZeroValue(character));
       }
 
-      // Safe to unwrap since this can't be anything other than a 1..=9 digit.
-      let digit = digit.to_digit(10).unwrap() as usize;
-      let magnitude = num::pow::pow(10, index);
+      if character_magnitude_map.insert(character, value).is_some() {
+        return Err(ConstructionError::DuplicateCharacter(character));
+      }
 
-      // Get all the units for this magnitude and intentionally leave them as
-      // `Result`s here. Since the default Roman numeral set only goes up to
-      // 4000, we can't require unit 5 and 10 for magnitude 1000 (5000, 10000).
-      // So once we go to add them to the result string, only then use `Result?`
-      // to get their characters.
-      let value_of_character = |m: usize| -> Result<String, ConversionError> {
-        self
-          .magnitude_character_map
-          .get(&m)
-          .map(ToString::to_string)
-          .ok_or(ConversionError::MissingMagnitude(m))
-      };
+      if magnitude_character_map.insert(value, character).is_some() {
+        return Err(ConstructionError::DuplicateValue(character));
+      }
+    }
+
+    let mut roman =
+      Self::from_maps(character_magnitude_map, magnitude_character_map);
+    roman.explicit_values = true;
+    roman.update_tables();
+    Ok(roman)
+  }
+
+  /// Sets the [`Notation`] used by [`Roman::to_string`] and
+  /// [`Roman::from_str_strict`].
+  ///
+  /// [`Roman::from_str`] accepts both notations regardless of this setting.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::{Notation, Roman};
+  ///
+  /// let roman = Roman::default().with_notation(Notation::Additive);
+  /// assert_eq!(roman.to_string(9).unwrap(), "VIIII");
+  /// assert_eq!(roman.from_str_strict::<i32>("VIIII").unwrap(), 9);
+  /// assert!(roman.from_str_strict::<i32>("IX").is_err());
+  ///
+  /// let custom = Roman::new(&['A', 'B']).with_notation(Notation::Additive);
+  /// assert_eq!(custom.to_string(9).unwrap(), "BAAAA");
+  /// ```
+  pub fn with_notation(mut self, notation: Notation) -> Self {
+    self.notation = notation;
+    self.update_tables();
+    self
+  }
+
+  /// Sets whether to use vinculum notation for numbers larger than the
+  /// character set can represent.
+  ///
+  /// A vinculum is written as U+0305 COMBINING OVERLINE after a character and
+  /// multiplies it by 1000, with each additional overline multiplying it by a
+  /// further 1000.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::Roman;
+  ///
+  /// let roman = Roman::default().with_vinculum(true);
+  /// assert_eq!(roman.to_string(3999).unwrap(), "MMMCMXCIX");
+  /// assert_eq!(roman.to_string(4000).unwrap(), "I\u{305}V\u{305}");
+  /// assert_eq!(roman.to_string(5_000_001).unwrap(), "V\u{305}\u{305}I");
+  /// assert_eq!(roman.from_str::<i32>("I\u{305}V\u{305}").unwrap(), 4000);
+  /// ```
+  pub fn with_vinculum(mut self, vinculum: bool) -> Self {
+    self.vinculum = vinculum;
+    self
+  }
 
-      let unit_1 = value_of_character(magnitude);
-      let unit_5 = value_of_character(magnitude * 5);
-      let unit_10 = value_of_character(magnitude * 10);
+  /// Sets whether to use apostrophus notation, as found in early printed books.
+  ///
+  /// In apostrophus notation U+2183 REVERSED C is used to write magnitudes of
+  /// 500 and above, so 500 = "IↃ", 1000 = "CIↃ", 5000 = "IↃↃ", 10000 = "CCIↃↃ"
+  /// and so on. When enabled, [`Roman::to_string`] writes these forms and
+  /// [`Roman::from_str`] accepts them, including when mixed with the regular
+  /// characters like "M" and "D".
+  ///
+  /// The "I" and "C" in these forms are the characters for magnitudes 1 and
+  /// 100 in the character set. Because "CIↃ" always reads as 1000, 500 is
+  /// still written with its regular character when the set has one, so that
+  /// 400 stays "CD". 900 stays "CM" the same way.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::Roman;
+  ///
+  /// let roman = Roman::default().with_apostrophus(true);
+  /// assert_eq!(roman.to_string(1500).unwrap(), "CIↃD");
+  /// assert_eq!(roman.to_string(10000).unwrap(), "CCIↃↃ");
+  /// assert_eq!(roman.from_str::<i32>("CIↃDCLXVI").unwrap(), 1666);
+  /// ```
+  pub fn with_apostrophus(mut self, apostrophus: bool) -> Self {
+    self.apostrophus = apostrophus;
+    self.update_tables();
+    self
+  }
+
+  /// Sets whether [`Roman::from_str`] accepts the Unicode Roman numeral
+  /// characters (U+2160 to U+2188) in addition to the character set.
+  ///
+  /// This includes the lowercase forms and the ligatures like Ⅻ, which can be
+  /// mixed with the regular characters.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::Roman;
+  ///
+  /// let roman = Roman::default().with_unicode_input(true);
+  /// assert_eq!(roman.from_str::<i32>("ⅯⅯⅩⅫ").unwrap(), 2022);
+  /// assert_eq!(roman.from_str::<i32>("MMXⅫ").unwrap(), 2022);
+  /// assert_eq!(roman.from_str::<i32>("ↂↁ").unwrap(), 15000);
+  /// ```
+  pub fn with_unicode_input(mut self, unicode_input: bool) -> Self {
+    self.unicode_input = unicode_input;
+    self
+  }
 
-      // Map the digit to its character, using magnitude 1 as examples.
-      result += &match digit {
-        // 1 through 3 equals I, II, III.
-        1..=3 => unit_1?.repeat(digit),
+  /// Sets whether [`Roman::to_string`] writes the Unicode Roman numeral
+  /// characters (U+2160 to U+2188) instead of the character set.
+  ///
+  /// The numbers 1 through 12 are written as their single character ligatures
+  /// like on clock faces, everything else is written one numeral per character.
+  /// Ligatures are only used when the rules write the same numerals, so with
+  /// additive notation 4 is still written "ⅠⅠⅠⅠ".
+  ///
+  /// Magnitudes missing from the character set are written with their Unicode
+  /// characters too, which go up to ↈ (100000). This raises the maximum of
+  /// the default set from 3999 to 399999, so numbers that return an error
+  /// without Unicode output can be written with it.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::Roman;
+  ///
+  /// let roman = Roman::default().with_unicode_output(true);
+  /// assert_eq!(roman.to_string(12).unwrap(), "Ⅻ");
+  /// assert_eq!(roman.to_string(2022).unwrap(), "ⅯⅯⅩⅩⅠⅠ");
+  /// assert_eq!(roman.to_string(15000).unwrap(), "ↂↁ");
+  /// assert!(Roman::default().to_string(15000).is_err());
+  /// ```
+  pub fn with_unicode_output(mut self, unicode_output: bool) -> Self {
+    self.unicode_output = unicode_output;
+    self.update_tables();
+    self
+  }
 
-        // 4 equals IV (note the reversed formatting).
-        4 => format!("{}{}", unit_5?, unit_1?),
+  /// Sets whether zero is written as "N" (nulla) instead of an empty string,
+  /// as done by Bede and in medieval computus tables.
+  ///
+  /// Nulla is left off when the character set uses "N" itself (or "n" with
+  /// [`Roman::with_minuscule`]), since 0 couldn't be told apart from that
+  /// character.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::Roman;
+  ///
+  /// let roman = Roman::default().with_nulla(true);
+  /// assert_eq!(roman.to_string(0).unwrap(), "N");
+  /// assert_eq!(roman.from_str::<i32>("N").unwrap(), 0);
+  /// ```
+  pub fn with_nulla(mut self, nulla: bool) -> Self {
+    self.nulla = nulla;
+    self
+  }
 
-        // 5 equals V.
-        5 => unit_5?,
+  /// Sets the prefix used for negative numbers, or [`None`] to return a
+  /// [`NegativeNumber`][ConversionError::NegativeNumber] error for them.
+  ///
+  /// When set, [`Roman::from_str`] also accepts the prefix and returns a
+  /// negative number for signed [`num::PrimInt`] types. An empty prefix can't
+  /// be told apart from no prefix at all, so it's the same as [`None`].
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::Roman;
+  ///
+  /// let roman = Roman::default().with_negative_sign(Some("−"));
+  /// assert_eq!(roman.to_string(-14).unwrap(), "−XIV");
+  /// assert_eq!(roman.from_str::<i32>("−XIV").unwrap(), -14);
+  /// assert!(roman.from_str::<u32>("−XIV").is_err());
+  /// ```
+  pub fn with_negative_sign(mut self, negative_sign: Option<&str>) -> Self {
+    self.negative_sign = negative_sign
+      .filter(|sign| !sign.is_empty())
+      .map(ToString::to_string);
+    self
+  }
 
-        // 6 through 8 equals VI, VII, VIII (also reversed).
-        6..=8 => format!("{}{}", unit_1?.repeat(digit - 5), unit_5?),
+  /// Sets whether [`Roman::to_string`] writes lowercase characters, as found
+  /// in manuscript transcriptions.
+  ///
+  /// When enabled, [`Roman::from_str`] also accepts the lowercase forms of the
+  /// characters in the set.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::Roman;
+  ///
+  /// let roman = Roman::default().with_minuscule(true);
+  /// assert_eq!(roman.to_string(2022).unwrap(), "mmxxii");
+  /// assert_eq!(roman.from_str::<i32>("mmxxii").unwrap(), 2022);
+  /// ```
+  pub fn with_minuscule(mut self, minuscule: bool) -> Self {
+    self.minuscule = minuscule;
+    self
+  }
 
-        // 9 equals IX (also reversed).
-        9 => format!("{}{}", unit_10?, unit_1?),
+  /// Sets whether [`Roman::to_string`] writes a final "i" as "j", so 8 is
+  /// written as "viij" in minuscule.
+  ///
+  /// When enabled, [`Roman::from_str`] also accepts a final "j" as an "i".
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::Roman;
+  ///
+  /// let roman = Roman::default().with_minuscule(true).with_terminal_j(true);
+  /// assert_eq!(roman.to_string(13).unwrap(), "xiij");
+  /// assert_eq!(roman.to_string(14).unwrap(), "xiv");
+  /// assert_eq!(roman.from_str::<i32>("viij").unwrap(), 8);
+  /// ```
+  pub fn with_terminal_j(mut self, terminal_j: bool) -> Self {
+    self.terminal_j = terminal_j;
+    self
+  }
 
-        _ => unreachable!(),
-      };
-    }
+  /// Sets the [`OverflowPolicy`] for numbers that are too large for the
+  /// character set, which defaults to [`OverflowPolicy::Error`].
+  ///
+  /// Use [`Roman::to_string_with_overflow`] to find out whether the policy was
+  /// applied to a number.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::{OverflowPolicy, Roman};
+  ///
+  /// let roman = Roman::default();
+  /// assert!(roman.to_string(5000).is_err());
+  ///
+  /// let decimal = Roman::default().with_overflow_policy(OverflowPolicy::Decimal);
+  /// assert_eq!(decimal.to_string(5000).unwrap(), "5000");
+  ///
+  /// let clamp = Roman::default().with_overflow_policy(OverflowPolicy::Clamp);
+  /// assert_eq!(clamp.to_string(5000).unwrap(), "MMMCMXCIX");
+  ///
+  /// let repeat = Roman::default().with_overflow_policy(OverflowPolicy::Repeat);
+  /// assert_eq!(repeat.to_string(5000).unwrap(), "MMMMM");
+  ///
+  /// let vinculum =
+  ///   Roman::default().with_overflow_policy(OverflowPolicy::Vinculum);
+  /// assert_eq!(vinculum.to_string(5000).unwrap(), "V\u{305}");
+  /// ```
+  pub fn with_overflow_policy(
+    mut self,
+    overflow_policy: OverflowPolicy,
+  ) -> Self {
+    self.overflow_policy = overflow_policy;
+    self
+  }
+
+  /// Sets the number of times [`OverflowPolicy::Repeat`] can repeat the
+  /// largest character before returning a
+  /// [`RepeatLimit`][ConversionError::RepeatLimit] error, which defaults to
+  /// 10000. Use [`None`] to repeat it without a limit, but note that the
+  /// whole [`String`] is allocated at once.
+  ///
+  /// Strings written with the repeated character are also accepted by
+  /// [`Roman::from_str_strict`].
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::{ConversionError, OverflowPolicy, Roman};
+  ///
+  /// let roman = Roman::default()
+  ///   .with_overflow_policy(OverflowPolicy::Repeat)
+  ///   .with_repeat_limit(Some(10));
+  ///
+  /// assert_eq!(roman.to_string(6212).unwrap(), "MMMMMMCCXII");
+  /// assert_eq!(roman.from_str_strict::<u64>("MMMMMMCCXII").unwrap(), 6212);
+  /// assert!(matches!(
+  ///   roman.to_string(u64::MAX),
+  ///   Err(ConversionError::RepeatLimit(10))
+  /// ));
+  /// ```
+  pub fn with_repeat_limit(mut self, repeat_limit: Option<usize>) -> Self {
+    self.repeat_limit = repeat_limit;
+    self
+  }
+
+  /// Converts a [`str`] to a generic integer [`num::PrimInt`].
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::Roman;
+  ///
+  /// let roman = Roman::default();
+  /// assert_eq!(roman.from_str::<i32>("IX").unwrap(), 9);
+  ///
+  /// let custom = Roman::new(&['A', 'B', 'C']);
+  /// assert_eq!(custom.from_str::<i32>("AC").unwrap(), 9);
+  /// ```
+  pub fn from_str<T: num::PrimInt>(
+    &self,
+    input: &str,
+  ) -> Result<T, ConversionError> {
+    self.parse(input, false)
+  }
+
+  /// Converts a [`str`] to a generic integer [`num::PrimInt`], only accepting
+  /// the canonical form that [`Roman::to_string`] would produce.
+  ///
+  /// Where [`Roman::from_str`] will happily return a number for inputs like
+  /// "IIII" or "IC", this returns an error describing why the input isn't
+  /// canonical.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::{ConversionError, Roman};
+  ///
+  /// let roman = Roman::default();
+  /// assert_eq!(roman.from_str_strict::<i32>("XIV").unwrap(), 14);
+  ///
+  /// assert!(matches!(
+  ///   roman.from_str_strict::<i32>("IIII"),
+  ///   Err(ConversionError::InvalidRepetition('I'))
+  /// ));
+  /// assert!(matches!(
+  ///   roman.from_str_strict::<i32>("IC"),
+  ///   Err(ConversionError::InvalidSubtraction('I', 'C'))
+  /// ));
+  /// assert!(matches!(
+  ///   roman.from_str_strict::<i32>("IIV"),
+  ///   Err(ConversionError::InvalidOrder('I'))
+  /// ));
+  /// ```
+  pub fn from_str_strict<T: num::PrimInt>(
+    &self,
+    input: &str,
+  ) -> Result<T, ConversionError> {
+    self.parse(input, true)
+  }
+
+  /// Converts a [`str`] to a generic integer [`num::PrimInt`], handling the
+  /// negative sign and nulla before decoding the rest of the input.
+  fn parse<T: num::PrimInt>(
+    &self,
+    input: &str,
+    strict: bool,
+  ) -> Result<T, ConversionError> {
+    self.parse_with(input, |input| {
+      let result = self.decode(input)?;
+      if strict {
+        self.check_canonical(input, result)?;
+      }
+
+      Ok(result)
+    })
+  }
+
+  /// Converts a [`str`] to a generic integer [`num::PrimInt`] like
+  /// [`Roman::parse`], using `decode` for the input without its sign.
+  fn parse_with<T: num::PrimInt>(
+    &self,
+    input: &str,
+    decode: impl FnOnce(&str) -> Result<i128, ConversionError>,
+  ) -> Result<T, ConversionError> {
+    let unsigned = self
+      .negative_sign
+      .as_deref()
+      .and_then(|sign| input.strip_prefix(sign));
+    let negative = unsigned.is_some();
+    let input = unsigned.unwrap_or(input);
+    if negative && input.is_empty() {
+      return Err(ConversionError::MissingNumeral);
+    }
+
+    let result = if self.is_nulla(input.as_bytes()) {
+      0
+    } else {
+      decode(input)?
+    };
+
+    Self::signed(result, negative)
+  }
+
+  /// Returns whether zero is written as nulla, which is only when nulla is
+  /// enabled and the character set doesn't use the same character.
+  fn writes_nulla(&self) -> bool {
+    let uses =
+      |character| self.character_magnitude_map.contains_key(&character);
+    self.nulla
+      && !uses(NULLA)
+      && !(self.minuscule && uses(NULLA.to_ascii_lowercase()))
+  }
+
+  /// Returns whether `input` is nulla, which is also written in lowercase
+  /// with [`Roman::with_minuscule`].
+  fn is_nulla(&self, input: &[u8]) -> bool {
+    // Nulla is ASCII, so it's always a single byte.
+    let nulla = NULLA as u8;
+    match input {
+      &[byte] if self.writes_nulla() => {
+        byte == nulla || (self.minuscule && byte == nulla.to_ascii_lowercase())
+      }
+      _ => false,
+    }
+  }
+
+  /// Converts a decoded `result` to a generic integer [`num::PrimInt`],
+  /// negating it first when the input had a negative sign.
+  fn signed<T: num::PrimInt>(
+    result: i128,
+    negative: bool,
+  ) -> Result<T, ConversionError> {
+    if !negative {
+      return T::from(result).ok_or(ConversionError::Overflow);
+    }
+
+    if T::min_value() == T::zero() {
+      return Err(ConversionError::NegativeNumber);
+    }
+
+    let result = result.checked_neg().ok_or(ConversionError::Overflow)?;
+    T::from(result).ok_or(ConversionError::Overflow)
+  }
+
+  /// Decodes the symbols in `input`, subtracting any symbol that comes before
+  /// one with 5 or 10 times its value (or the steps of a custom pattern).
+  fn decode(&self, input: &str) -> Result<i128, ConversionError> {
+    // ASCII input can't contain a vinculum, apostrophus or Unicode numeral, so
+    // only the styles need the full symbols.
+    if self.tables.ascii_table()
+      && input.is_ascii()
+      && !self.minuscule
+      && !self.terminal_j
+    {
+      return self.decode_ascii(input.as_bytes(), |_, byte| {
+        ConversionError::InvalidCharacter(char::from(byte))
+      });
+    }
+
+    self.decode_symbols(&self.symbols(input)?)
+  }
+
+  /// Decodes `symbols` like [`Roman::decode`].
+  fn decode_symbols(
+    &self,
+    symbols: &[Symbol],
+  ) -> Result<i128, ConversionError> {
+    // Accumulate in an `i128` so subtractive pairs at the start of the input
+    // (like "IV") don't underflow unsigned types before the addition happens.
+    let mut result = 0_i128;
+
+    for (index, symbol) in symbols.iter().enumerate() {
+      let generic_value =
+        i128::try_from(symbol.value).map_err(|_| ConversionError::Overflow)?;
+
+      let subtract = match symbols.get(index + 1) {
+        Some(next) if !symbol.ligature && symbol.value < next.value => {
+          self.is_decoded_subtraction(symbol.value, next.value)
+        }
+        _ => false,
+      };
+
+      result = if subtract {
+        result.checked_sub(generic_value)
+      } else {
+        result.checked_add(generic_value)
+      }
+      .ok_or(ConversionError::Overflow)?;
+    }
+
+    Ok(result)
+  }
+
+  /// Decodes ASCII `input` like [`Roman::decode`], looking the value of each
+  /// byte up in the [`Tables`] without collecting any symbols.
+  ///
+  /// A byte that isn't in the set returns the error `invalid` creates from its
+  /// offset and the byte itself.
+  fn decode_ascii(
+    &self,
+    input: &[u8],
+    invalid: impl Fn(usize, u8) -> ConversionError,
+  ) -> Result<i128, ConversionError> {
+    let mut values = input.iter().enumerate().map(|(offset, &byte)| {
+      self
+        .tables
+        .ascii_value(byte)
+        .ok_or_else(|| invalid(offset, byte))
+    });
+
+    let mut result = 0_i128;
+    let Some(first) = values.next() else {
+      return Ok(result);
+    };
+
+    let mut current = first?;
+    for next in values {
+      let next = next?;
+      let value =
+        i128::try_from(current).map_err(|_| ConversionError::Overflow)?;
+
+      result =
+        if current < next && self.is_decoded_subtraction(current, next) {
+          result.checked_sub(value)
+        } else {
+          result.checked_add(value)
+        }
+        .ok_or(ConversionError::Overflow)?;
+
+      current = next;
+    }
+
+    let value =
+      i128::try_from(current).map_err(|_| ConversionError::Overflow)?;
+    result.checked_add(value).ok_or(ConversionError::Overflow)
+  }
+
+  /// Checks that `input` is the canonical form of `result`, returning an error
+  /// describing why it isn't otherwise.
+  fn check_canonical(
+    &self,
+    input: &str,
+    result: i128,
+  ) -> Result<(), ConversionError> {
+    let number =
+      u128::try_from(result).map_err(|_| ConversionError::Overflow)?;
+    let canonical = self.encode(number);
+    if matches!(&canonical, Ok(canonical) if canonical == input) {
+      return Ok(());
+    }
+
+    let symbols = self.symbols(input)?;
+    let mut repetitions = 1;
+
+    for pair in symbols.windows(2) {
+      let (symbol, next) = (pair[0], pair[1]);
+
+      if symbol.value == next.value {
+        repetitions += 1;
+        if repetitions > self.maximum_repetitions(symbol.value) {
+          return Err(ConversionError::InvalidRepetition(symbol.character));
+        }
+      } else {
+        repetitions = 1;
+      }
+
+      if symbol.value < next.value
+        && !self.is_subtractive_pair(symbol.value, next.value)
+      {
+        return Err(ConversionError::InvalidSubtraction(
+          symbol.character,
+          next.character,
+        ));
+      }
+    }
+
+    // At this point the symbols and pairs are all valid on their own, so
+    // anything that doesn't match the canonical form must be out of order.
+    // The canonical form can have characters that aren't accepted as input
+    // (like Unicode output without Unicode input), in which case the very
+    // first symbol is already out of order. Symbols are compared by value so
+    // aliases of the canonical characters are accepted.
+    let canonical = self.symbols(&canonical?).unwrap_or_default();
+    let mismatch = symbols
+      .iter()
+      .zip(&canonical)
+      .position(|(symbol, canonical)| symbol.value != canonical.value);
+
+    let index = match mismatch {
+      Some(index) => index,
+      None if symbols.len() == canonical.len() => return Ok(()),
+      None => canonical.len().min(symbols.len().saturating_sub(1)),
+    };
+
+    match symbols.get(index) {
+      Some(symbol) => Err(ConversionError::InvalidOrder(symbol.character)),
+      None => Ok(()),
+    }
+  }
+
+  /// Converts a generic integer [`num::PrimInt`] to a [`String`].
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::Roman;
+  ///
+  /// let roman = Roman::default();
+  /// assert_eq!(roman.to_string(9).unwrap(), "IX");
+  ///
+  /// let custom = Roman::new(&['A', 'B', 'C']);
+  /// assert_eq!(custom.to_string(9).unwrap(), "AC");
+  /// ```
+  pub fn to_string<T: num::PrimInt + ToString>(
+    &self,
+    number: T,
+  ) -> Result<String, ConversionError> {
+    Ok(self.to_string_with_overflow(number)?.0)
+  }
+
+  /// Converts a generic integer [`num::PrimInt`] to a [`String`] like
+  /// [`Roman::to_string`], also returning the [`OverflowPolicy`] if it was
+  /// applied because the number is too large for the character set.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::{OverflowPolicy, Roman};
+  ///
+  /// let roman = Roman::default().with_overflow_policy(OverflowPolicy::Decimal);
+  /// assert_eq!(
+  ///   roman.to_string_with_overflow(1000).unwrap(),
+  ///   ("M".to_string(), None)
+  /// );
+  /// assert_eq!(
+  ///   roman.to_string_with_overflow(10_000).unwrap(),
+  ///   ("10000".to_string(), Some(OverflowPolicy::Decimal))
+  /// );
+  /// ```
+  pub fn to_string_with_overflow<T: num::PrimInt + ToString>(
+    &self,
+    number: T,
+  ) -> Result<(String, Option<OverflowPolicy>), ConversionError> {
+    let mut result = String::new();
+    let overflow = self.write_number(number, &mut result)?;
+    Ok((result, overflow))
+  }
+
+  /// Writes a generic integer [`num::PrimInt`] to `output` like
+  /// [`Roman::to_string_with_overflow`].
+  ///
+  /// The output can be partially written when an error is returned, see
+  /// [`Roman::write_to`] for a version that checks the number first.
+  fn write_number<T: num::PrimInt>(
+    &self,
+    number: T,
+    output: &mut dyn fmt::Write,
+  ) -> Result<Option<OverflowPolicy>, ConversionError> {
+    if number >= T::zero() {
+      let number =
+        number.to_u128().ok_or(ConversionError::GenericConversion)?;
+      return self.encode_with_overflow(number, output);
+    }
+
+    match &self.negative_sign {
+      Some(sign) => {
+        let number = number
+          .to_i128()
+          .ok_or(ConversionError::GenericConversion)?
+          .unsigned_abs();
+        output.write_str(sign)?;
+        self.encode_with_overflow(number, output)
+      }
+      None => Err(ConversionError::NegativeNumber),
+    }
+  }
+
+  /// Converts a non-negative number to a [`String`], used by both
+  /// [`Roman::to_string`] and [`Roman::from_str_strict`].
+  fn encode(&self, number: u128) -> Result<String, ConversionError> {
+    let mut result = String::new();
+    self.encode_with_overflow(number, &mut result)?;
+    Ok(result)
+  }
+
+  /// Writes a non-negative number to `output` with styling applied, returning
+  /// the [`OverflowPolicy`] if it was applied.
+  fn encode_with_overflow(
+    &self,
+    number: u128,
+    output: &mut dyn fmt::Write,
+  ) -> Result<Option<OverflowPolicy>, ConversionError> {
+    let mut styled = Styled::new(self, output);
+
+    // The number has to be checked before writing anything when there's a
+    // policy to fall back to, since the output can't be taken back.
+    let overflow = match self.overflow_policy {
+      OverflowPolicy::Error => {
+        self.encode_numerals(number, self.vinculum, &mut styled)?;
+        None
+      }
+      _ => {
+        let check = &mut CharCounter::default();
+        match self.encode_numerals(number, self.vinculum, check) {
+          Err(error) if is_overflow_error(&error) => {
+            self.encode_overflow(number, error, &mut styled)?;
+            Some(self.overflow_policy)
+          }
+          result => {
+            result?;
+            self.encode_numerals(number, self.vinculum, &mut styled)?;
+            None
+          }
+        }
+      }
+    };
+
+    styled.finish()?;
+    Ok(overflow)
+  }
+
+  /// Writes a non-negative number that's too large for the character set to
+  /// `output` using the [`OverflowPolicy`], or returns the original `error`
+  /// when the policy doesn't apply.
+  fn encode_overflow(
+    &self,
+    number: u128,
+    error: ConversionError,
+    output: &mut dyn fmt::Write,
+  ) -> Result<(), ConversionError> {
+    match self.overflow_policy {
+      OverflowPolicy::Error => Err(error),
+      OverflowPolicy::Decimal => Ok(write!(output, "{number}")?),
+      OverflowPolicy::Clamp => {
+        // Numbers below the maximum that can't be written (ie. 16 without
+        // 'I') aren't clamped.
+        let maximum = self.maximum()?;
+        if number < maximum {
+          return Err(error);
+        }
+
+        self.encode_numerals(maximum, self.vinculum, output)
+      }
+      OverflowPolicy::Repeat => {
+        // Safe to unwrap since the character set is never empty.
+        let (&largest, _) = self
+          .magnitude_character_map
+          .iter()
+          .max_by_key(|&(value, _)| value)
+          .unwrap();
+
+        let count = usize::try_from(number / largest as u128)
+          .map_err(|_| ConversionError::Overflow)?;
+        if let Some(limit) = self.repeat_limit.filter(|&limit| count > limit) {
+          return Err(ConversionError::RepeatLimit(limit));
+        }
+
+        let remainder = number % largest as u128;
+        for _ in 0..count {
+          self.write_magnitude(largest, output)?;
+        }
+
+        if remainder > 0 {
+          self.encode_numerals(remainder, self.vinculum, output)?;
+        }
+
+        Ok(())
+      }
+      OverflowPolicy::Vinculum => self.encode_numerals(number, true, output),
+    }
+  }
+
+  /// Returns the largest number the character set can write, or a
+  /// [`MissingMagnitude`][ConversionError::MissingMagnitude] error if it
+  /// can't write any.
+  fn maximum(&self) -> Result<u128, ConversionError> {
+    let mut maximum = if self.explicit_values {
+      self.maximum_greedy()
+    } else {
+      self.maximum_digits()
+    };
+
+    if maximum == 0 {
+      return Err(ConversionError::MissingMagnitude(1));
+    }
+
+    // With a vinculum the thousands can go up to the maximum as well.
+    if self.vinculum {
+      let base = maximum;
+      while let Some(next) = maximum
+        .checked_mul(1000)
+        .and_then(|next| next.checked_add(base.min(999)))
+        .filter(|&next| {
+          let check = &mut CharCounter::default();
+          self.encode_numerals(next, true, check).is_ok()
+        })
+      {
+        maximum = next;
+      }
+    }
+
+    Ok(maximum)
+  }
+
+  /// Returns the largest number the character set can write digit by digit,
+  /// which is the largest digit it can write at every magnitude.
+  fn maximum_digits(&self) -> u128 {
+    let mut maximum = 0_u128;
+    let mut magnitude = Some(1_usize);
+
+    while let Some(current) = magnitude {
+      let digit = (1..self.radix).rev().find(|&digit| {
+        let check = &mut CharCounter::default();
+        self.encode_digit(digit, magnitude, check).is_ok()
+      });
+
+      let Some(digit) = digit else {
+        break;
+      };
+
+      match (digit as u128)
+        .checked_mul(current as u128)
+        .and_then(|value| maximum.checked_add(value))
+      {
+        Some(next) => maximum = next,
+        None => break,
+      }
+
+      magnitude = current.checked_mul(self.radix);
+    }
+
+    maximum
+  }
+
+  /// Returns the largest number the character set can write greedily, taking
+  /// as many of each value as allowed while keeping the rest smaller than it.
+  fn maximum_greedy(&self) -> u128 {
+    let mut maximum = 0_u128;
+    let mut bound = u128::MAX;
+
+    for &(value, ref magnitudes) in self.greedy_table() {
+      let value = value as u128;
+      if value >= bound {
+        continue;
+      }
+
+      let count =
+        ((bound - 1) / value).min(self.greedy_repetitions(magnitudes) as u128);
+      maximum += count * value;
+      bound = value.min(bound - count * value);
+    }
+
+    maximum
+  }
+
+  /// Returns every character and subtractive pair (ie. "M", "CM", "D", "CD",
+  /// "C") as its value and magnitudes, from the largest value to the smallest
+  /// with single characters before subtractive pairs of the same value.
+  fn greedy_table(&self) -> &[(usize, Vec<usize>)] {
+    self.tables.greedy()
+  }
+
+  /// Returns the number of times an entry of the [`Roman::greedy_table`] can
+  /// be used in a row, where subtractive pairs are never repeated.
+  fn greedy_repetitions(&self, magnitudes: &[usize]) -> usize {
+    match magnitudes {
+      &[magnitude] => self.maximum_repetitions(magnitude),
+      _ => 1,
+    }
+  }
+
+  /// Writes a non-negative number to `output` without applying any styling
+  /// to it.
+  fn encode_numerals(
+    &self,
+    number: u128,
+    vinculum: bool,
+    output: &mut dyn fmt::Write,
+  ) -> Result<(), ConversionError> {
+    if self.writes_nulla() && number == 0 {
+      return Ok(output.write_char(NULLA)?);
+    }
+
+    if let Some(numeral) = self.lookup(number) {
+      return Ok(output.write_str(numeral)?);
+    }
+
+    self.compute_numerals(number, vinculum, output)
+  }
+
+  /// Writes a non-negative number to `output` like
+  /// [`Roman::encode_numerals`] without using the lookup table, which is how
+  /// the lookup table itself is built.
+  fn compute_numerals(
+    &self,
+    number: u128,
+    vinculum: bool,
+    output: &mut dyn fmt::Write,
+  ) -> Result<(), ConversionError> {
+    // Clock faces use the single character ligatures for 1 through 12, as
+    // long as they stand for what the rules would write.
+    if self.unicode_output && self.tables.ligature(number) {
+      let ligature = UNICODE_NUMERALS[number as usize - 1].0;
+      return Ok(output.write_char(ligature)?);
+    }
+
+    if !vinculum || number < 1000 {
+      return self.encode_digits(number, output);
+    }
+
+    match self.encode_digits(number, &mut CharCounter::default()) {
+      // When the number is too large for the character set, write the
+      // thousands with a vinculum over them and the remainder as normal.
+      Err(
+        ConversionError::MissingMagnitude(_)
+        | ConversionError::UnreachableValue(_),
+      ) => {
+        self.encode_numerals(number / 1000, vinculum, &mut Overline(output))?;
+        self.encode_digits(number % 1000, output)
+      }
+      result => {
+        result?;
+        self.encode_digits(number, output)
+      }
+    }
+  }
+
+  /// Writes a non-negative number to `output` digit by digit using only the
+  /// characters in the set.
+  fn encode_digits(
+    &self,
+    number: u128,
+    output: &mut dyn fmt::Write,
+  ) -> Result<(), ConversionError> {
+    if self.explicit_values {
+      return self.encode_greedy(number, output);
+    }
+
+    let radix =
+      u128::try_from(self.radix).map_err(|_| ConversionError::Overflow)?;
+
+    // Start at the largest magnitude in the number so the digits can be
+    // written in order without collecting them first.
+    let mut divisor = 1_u128;
+    let mut exponent = 0;
+    while number / divisor >= radix {
+      divisor *= radix;
+      exponent += 1;
+    }
+
+    while divisor > 0 {
+      // Safe to cast since the digit is always smaller than the radix.
+      let digit = (number / divisor % radix) as usize;
+
+      // Skip any zeroes in the number since we don't have to do anything for
+      // it, and only fall back to writing the digit when it isn't in the
+      // table (which also returns the error for it).
+      if digit != 0 {
+        match self.tables.digit(self.radix, exponent, digit) {
+          Some(written) => output.write_str(written)?,
+          None => {
+            let magnitude = usize::try_from(divisor).ok();
+            self.encode_digit(digit, magnitude, output)?;
+          }
+        }
+      }
+
+      divisor /= radix;
+      exponent = exponent.saturating_sub(1);
+    }
+
+    Ok(())
+  }
+
+  /// Writes a non-negative number to `output` by taking the largest values
+  /// that fit first, for character sets with explicit values.
+  fn encode_greedy(
+    &self,
+    number: u128,
+    output: &mut dyn fmt::Write,
+  ) -> Result<(), ConversionError> {
+    let mut remaining = number;
+
+    for &(value, ref magnitudes) in self.greedy_table() {
+      let value = value as u128;
+      // Anything that would need more repetitions than allowed is left for
+      // the smaller characters to write.
+      let count =
+        (remaining / value).min(self.greedy_repetitions(magnitudes) as u128);
+
+      for _ in 0..count {
+        match magnitudes[..] {
+          [smaller, larger] => self.write_pair(smaller, larger, output)?,
+          _ => {
+            for &magnitude in magnitudes {
+              self.write_magnitude(magnitude, output)?;
+            }
+          }
+        }
+      }
+
+      remaining -= count * value;
+    }
+
+    if remaining > 0 {
+      return Err(ConversionError::UnreachableValue(remaining));
+    }
+
+    Ok(())
+  }
+
+  /// Writes a single non-zero `digit` at `magnitude` to `output`, or returns
+  /// an error when it can't be written with the character set.
+  fn encode_digit(
+    &self,
+    digit: usize,
+    magnitude: Option<usize>,
+    output: &mut dyn fmt::Write,
+  ) -> Result<(), ConversionError> {
+    // Get the units for this magnitude only when they're needed. Since the
+    // default Roman numeral set only goes up to 4000, we can't require unit
+    // 5 and 10 for magnitude 1000 (5000, 10000).
+    let magnitude_of = |factor: usize| {
+      magnitude
+        .and_then(|m| m.checked_mul(factor))
+        .ok_or(ConversionError::Overflow)
+    };
+    let unit = magnitude_of(1)?;
+
+    // Using magnitude 1 of the default pattern as examples, a digit right
+    // below the next step (4 and 9) is written by subtracting a unit from it
+    // (IV and IX) when that subtraction is allowed.
+    // Safe to unwrap since the radix is always larger than the digit.
+    let next = *self.steps().find(|&&step| step > digit).unwrap();
+    if digit + 1 == next
+      && !self.pattern.contains(&digit)
+      && self.is_subtractive_pair(unit, magnitude_of(next)?)
+    {
+      return self.write_pair(unit, magnitude_of(next)?, output);
+    }
+
+    // Otherwise it's the largest value in the pattern that fits followed by
+    // repeated units (I, II, III, IIII, V, VI, VII, VIII, VIIII).
+    // Safe to unwrap since the pattern always starts with 1.
+    let base = *self.pattern.iter().rfind(|&&value| value <= digit).unwrap();
+    let count = match base {
+      1 => digit,
+      _ => {
+        self.write_magnitude(magnitude_of(base)?, output)?;
+        digit - base
+      }
+    };
+
+    if count > self.maximum_repetitions(unit) {
+      let mut characters = String::new();
+      self.write_magnitude(unit, &mut characters)?;
+
+      // Safe to unwrap since every magnitude has at least one character.
+      let character = characters.chars().next().unwrap();
+      return Err(ConversionError::InvalidRepetition(character));
+    }
+
+    for _ in 0..count {
+      self.write_magnitude(unit, output)?;
+    }
+
+    Ok(())
+  }
+
+  /// Returns the values in the pattern after the unit followed by the radix,
+  /// which are the multiples of a unit that it can be subtracted from.
+  fn steps(&self) -> impl Iterator<Item = &usize> {
+    self
+      .pattern
+      .iter()
+      .skip(1)
+      .chain(core::iter::once(&self.radix))
+  }
+
+  /// Writes the subtractive pair of `smaller` before `larger` to `output`.
+  fn write_pair(
+    &self,
+    smaller: usize,
+    larger: usize,
+    output: &mut dyn fmt::Write,
+  ) -> Result<(), ConversionError> {
+    self.write_magnitude(smaller, output)?;
+
+    // A hundred before an apostrophus reads as part of it (ie. 900 as "CCIↃ"
+    // like the start of "CCIↃↃ"), so the larger one keeps its regular
+    // character when the set has one, the same way 400 stays "CD".
+    if smaller == 100
+      && !self.unicode_output
+      && self.apostrophus_of_magnitude(larger).is_some()
+    {
+      if let Some(&character) = self.magnitude_character_map.get(&larger) {
+        return Ok(output.write_char(character)?);
+      }
+    }
+
+    self.write_magnitude(larger, output)
+  }
+
+  /// Writes the characters for `magnitude` to `output` or returns a
+  /// [`MissingMagnitude`][ConversionError::MissingMagnitude] error.
+  fn write_magnitude(
+    &self,
+    magnitude: usize,
+    output: &mut dyn fmt::Write,
+  ) -> Result<(), ConversionError> {
+    if self.unicode_output {
+      let unicode = UNICODE_NUMERALS
+        .iter()
+        .find(|&&(_, value)| value == magnitude);
+
+      if let Some(&(character, _)) = unicode {
+        return Ok(output.write_char(character)?);
+      }
+    }
+
+    if let Some(runs) = self.apostrophus_of_magnitude(magnitude) {
+      for (character, count) in runs {
+        for _ in 0..count {
+          output.write_char(character)?;
+        }
+      }
+
+      return Ok(());
+    }
+
+    let character = self
+      .magnitude_character_map
+      .get(&magnitude)
+      .ok_or(ConversionError::MissingMagnitude(magnitude))?;
+    Ok(output.write_char(*character)?)
+  }
+
+  /// Returns the apostrophus form of `magnitude` as runs of repeated
+  /// characters if apostrophus notation is enabled and `magnitude` has one
+  /// (ie. 1000 = "CIↃ" and 5000 = "IↃↃ").
+  ///
+  /// 500 is only written as "IↃ" when the character set has no character for
+  /// it, since 400 would otherwise be written as "CIↃ" and read back as 1000.
+  /// For the same reason [`Roman::write_pair`] writes 900 as "CM".
+  fn apostrophus_of_magnitude(
+    &self,
+    magnitude: usize,
+  ) -> Option<[(char, usize); 3]> {
+    let in_set = self.magnitude_character_map.contains_key(&magnitude);
+    if !self.apostrophus || magnitude < 500 || (magnitude == 500 && in_set) {
+      return None;
+    }
+
+    let mut significand = magnitude;
+    let mut exponent = 0;
+    while significand % 10 == 0 {
+      significand /= 10;
+      exponent += 1;
+    }
+
+    let one = *self.magnitude_character_map.get(&1)?;
+
+    match significand {
+      // 10^n is written as n - 2 Cs, an I and n - 2 reversed Cs.
+      1 => {
+        let hundred = *self.magnitude_character_map.get(&100)?;
+        Some([
+          (hundred, exponent - 2),
+          (one, 1),
+          (APOSTROPHUS, exponent - 2),
+        ])
+      }
+
+      // 5 * 10^n is written as an I followed by n - 1 reversed Cs.
+      5 => Some([(one, 0), (one, 1), (APOSTROPHUS, exponent - 1)]),
+
+      _ => None,
+    }
+  }
+
+  /// Creates the [`Symbol`] for an apostrophus with `closing` reversed Cs,
+  /// removing the opening Cs that belong to it from the end of `symbols`.
+  fn apostrophus_symbol(
+    &self,
+    symbols: &mut Vec<Symbol>,
+    closing: usize,
+  ) -> Result<Symbol, ConversionError> {
+    let hundred = self.magnitude_character_map.get(&100);
+    let opening = symbols
+      .iter()
+      .rev()
+      .take_while(|symbol| {
+        Some(&symbol.character) == hundred && symbol.value == 100
+      })
+      .count();
+
+    let power_of_ten = |exponent: usize| {
+      u32::try_from(exponent)
+        .ok()
+        .and_then(|exponent| 10_usize.checked_pow(exponent))
+        .ok_or(ConversionError::Overflow)
+    };
+
+    let value = if opening == 0 {
+      // Without any opening Cs, IↃ is 500, IↃↃ is 5000 and so on.
+      power_of_ten(closing + 1)?
+        .checked_mul(5)
+        .ok_or(ConversionError::Overflow)?
+    } else if opening >= closing {
+      // With opening Cs, CIↃ is 1000, CCIↃↃ is 10000 and so on. Any extra Cs
+      // in front are regular hundreds, ie. CCIↃ is C followed by CIↃ.
+      symbols.truncate(symbols.len() - closing);
+      power_of_ten(closing + 2)?
+    } else {
+      return Err(ConversionError::InvalidCharacter(APOSTROPHUS));
+    };
+
+    Ok(Symbol {
+      character: APOSTROPHUS,
+      value,
+      ligature: false,
+    })
+  }
+
+  /// Splits `input` into its [`Symbol`]s, applying any vinculums to the
+  /// character they are above and combining any apostrophus forms.
+  fn symbols(&self, input: &str) -> Result<Vec<Symbol>, ConversionError> {
+    self.indexed_symbols(input).map_err(|(_, error)| error)
+  }
+
+  /// Returns whether a vinculum in the input multiplies the character before
+  /// it, which is whenever one can be written.
+  fn reads_vinculum(&self) -> bool {
+    self.vinculum || self.overflow_policy == OverflowPolicy::Vinculum
+  }
+
+  /// Splits `input` into its [`Symbol`]s like [`Roman::symbols`], returning
+  /// the index of the character that caused an error along with it.
+  fn indexed_symbols(
+    &self,
+    input: &str,
+  ) -> Result<Vec<Symbol>, (usize, ConversionError)> {
+    // Normalizing replaces every character with exactly one other, so the
+    // indices still match the original input.
+    let normalized;
+    let input = if self.minuscule || self.terminal_j {
+      normalized = self.normalize_style(input);
+      &normalized
+    } else {
+      input
+    };
+
+    let mut symbols: Vec<Symbol> = Vec::new();
+    let mut characters = input.chars().enumerate().peekable();
+
+    while let Some((index, character)) = characters.next() {
+      let at = |error| (index, error);
+
+      if self.reads_vinculum() && character == VINCULUM {
+        let symbol = symbols
+          .last_mut()
+          .ok_or(ConversionError::InvalidCharacter(character))
+          .map_err(at)?;
+        symbol.value = symbol
+          .value
+          .checked_mul(1000)
+          .ok_or(ConversionError::Overflow)
+          .map_err(at)?;
+        continue;
+      }
+
+      if self.apostrophus
+        && characters.peek().map(|&(_, next)| next) == Some(APOSTROPHUS)
+        && self.magnitude_character_map.get(&1) == Some(&character)
+      {
+        let mut closing = 0;
+        while characters
+          .next_if(|&(_, next)| next == APOSTROPHUS)
+          .is_some()
+        {
+          closing += 1;
+        }
+
+        let symbol =
+          self.apostrophus_symbol(&mut symbols, closing).map_err(at)?;
+        symbols.push(symbol);
+        continue;
+      }
+
+      let value = if character.is_ascii() && self.tables.ascii_table() {
+        self.tables.ascii_value(character as u8)
+      } else {
+        self.character_magnitude_map.get(&character).copied()
+      };
+
+      let symbol = match value {
+        Some(value) => Symbol {
+          character,
+          value,
+          ligature: false,
+        },
+        None => self.unicode_symbol(character).map_err(at)?,
+      };
+
+      symbols.push(symbol);
+    }
+
+    Ok(symbols)
+  }
+
+  /// Converts the minuscule characters and terminal "j" in `input` back to the
+  /// characters they stand for.
+  fn normalize_style(&self, input: &str) -> String {
+    let is_known = |character: char| {
+      self.character_magnitude_map.contains_key(&character)
+        || character == APOSTROPHUS
+        || UNICODE_NUMERALS
+          .iter()
+          .any(|&(unicode, _)| unicode == character)
+    };
+
+    let mut normalized = String::with_capacity(input.len());
+    for (index, mut character) in input.char_indices() {
+      let terminal = index + character.len_utf8() == input.len();
+      if self.terminal_j && terminal {
+        character = match character {
+          'j' => 'i',
+          'J' => 'I',
+          character => character,
+        };
+      }
+
+      let mut uppercase = character.to_uppercase();
+      if let (true, Some(upper), None) =
+        (self.minuscule, uppercase.next(), uppercase.next())
+      {
+        if !is_known(character) && is_known(upper) {
+          character = upper;
+        }
+      }
+
+      normalized.push(character);
+    }
+
+    normalized
+  }
+
+  /// Creates the [`Symbol`] for a Unicode Roman numeral character if Unicode
+  /// input is enabled, or returns an
+  /// [`InvalidCharacter`][ConversionError::InvalidCharacter] error.
+  fn unicode_symbol(&self, character: char) -> Result<Symbol, ConversionError> {
+    let value = UNICODE_NUMERALS
+      .iter()
+      .find(|(unicode, _)| self.unicode_input && *unicode == character)
+      .map(|&(_, value)| value)
+      .ok_or(ConversionError::InvalidCharacter(character))?;
+
+    // Ligatures like Ⅻ are the only numerals that aren't a 1 or a 5 followed
+    // by zeroes.
+    let single = Self::is_power_of(value, 10)
+      || (value % 5 == 0 && Self::is_power_of(value / 5, 10));
+
+    Ok(Symbol {
+      character,
+      value,
+      ligature: !single,
+    })
+  }
+
+  /// Returns whether `value` is a power of the radix (ie. 'I', 'X', 'C' and
+  /// 'M'), the only magnitudes that can be repeated or subtracted in the
+  /// canonical form by default.
+  fn is_unit(&self, value: usize) -> bool {
+    Self::is_power_of(value, self.radix)
+  }
+
+  /// Returns whether `value` is a power of `radix`.
+  fn is_power_of(value: usize, radix: usize) -> bool {
+    let mut unit = 1_usize;
+    while unit < value {
+      match unit.checked_mul(radix) {
+        Some(next) => unit = next,
+        None => return false,
+      }
+    }
+
+    unit == value
+  }
+
+  /// Returns the number of times a character with magnitude `value` can appear
+  /// in a row in the canonical form.
+  fn maximum_repetitions(&self, value: usize) -> usize {
+    let repeatable = match &self.repeatable {
+      Some(repeatable) => repeatable.contains(&value),
+      None => self.is_unit(value),
+    };
+
+    // By default a unit can be repeated until it reaches the next step in the
+    // pattern (IIII before V), or one less when it can be subtracted from it
+    // instead (III before IV).
+    let largest_gap = core::iter::once(&1)
+      .chain(self.steps())
+      .zip(self.steps())
+      .map(|(value, step)| step - value)
+      .max()
+      .unwrap_or(1);
+    let default = largest_gap
+      .saturating_sub(1)
+      .saturating_sub(usize::from(self.allows_subtraction()));
+
+    match self.repetition_limit {
+      _ if !repeatable => 1,
+      Some(limit) => limit,
+      None => default.max(1),
+    }
+  }
+
+  /// Returns whether subtractive pairs are used in the canonical form.
+  fn allows_subtraction(&self) -> bool {
+    self.notation == Notation::Subtractive && self.subtraction
+  }
+
+  /// Returns whether `smaller` can be subtracted from `larger` in the canonical
+  /// form (ie. "IV" and "IX" but not "IL" or "VX").
+  fn is_subtractive_pair(&self, smaller: usize, larger: usize) -> bool {
+    if !self.allows_subtraction() {
+      return false;
+    }
+
+    match &self.subtractive_pairs {
+      Some(pairs) => pairs.contains(&(smaller, larger)),
+      None => self.is_unit(smaller) && self.is_next_magnitude(smaller, larger),
+    }
+  }
+
+  /// Returns whether `smaller` is subtracted from `larger` by
+  /// [`Roman::from_str`], which is more lenient than the canonical form and
+  /// accepts pairs like "VL" regardless of the [`Notation`].
+  fn is_decoded_subtraction(&self, smaller: usize, larger: usize) -> bool {
+    if !self.subtraction {
+      return false;
+    }
+
+    match &self.subtractive_pairs {
+      Some(pairs) => pairs.contains(&(smaller, larger)),
+      None => self.is_next_magnitude(smaller, larger),
+    }
+  }
 
-    Ok(result.chars().rev().collect())
+  /// Returns whether `larger` is one of the steps times `smaller` (ie. 5 or 10
+  /// times in the default pattern).
+  fn is_next_magnitude(&self, smaller: usize, larger: usize) -> bool {
+    self
+      .steps()
+      .any(|&step| smaller.checked_mul(step) == Some(larger))
   }
 }
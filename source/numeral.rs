@@ -0,0 +1,231 @@
+//! The [`RomanNumeral`] value type, a number together with the [`Roman`] it's
+//! written in.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Div, Mul, Sub};
+use core::str::FromStr;
+
+use crate::{default_roman, CharCounter, ConversionError, Roman};
+
+/// A number that can be written in a [`Roman`] numeral system, checked when
+/// it's created.
+///
+/// Numerals compare, sort and hash by their value regardless of how they were
+/// written or which system they belong to. Arithmetic is checked, returning an
+/// error when the result can't be written in the system of the left operand.
+///
+/// Numerals from [`FromStr`] and [`TryFrom`] use [`Roman::default`], while
+/// [`Roman::numeral`] and [`Roman::numeral_from_str`] use a custom system.
+///
+/// ## Example
+///
+/// ```rust
+/// use romantic::{Notation, Roman, RomanNumeral};
+///
+/// let fourteen: RomanNumeral = "XIV".parse().unwrap();
+/// assert_eq!(fourteen.to_string(), "XIV");
+/// assert_eq!(format!("{fourteen:#}"), "xiv");
+/// assert_eq!(fourteen.value(), 14);
+///
+/// let sum = (fourteen + RomanNumeral::try_from(6).unwrap()).unwrap();
+/// assert_eq!(sum.to_string(), "XX");
+/// assert!(RomanNumeral::try_from(4000).is_err());
+///
+/// let additive = Roman::default().with_notation(Notation::Additive);
+/// let other = additive.numeral_from_str("XIIII").unwrap();
+/// assert_eq!(other, fourteen);
+/// assert_eq!(other.to_string(), "XIIII");
+/// ```
+#[derive(Clone, Copy)]
+pub struct RomanNumeral<'a> {
+  /// The value of the numeral.
+  value: i128,
+
+  /// The system the numeral is written in.
+  roman: &'a Roman,
+}
+
+impl<'a> RomanNumeral<'a> {
+  /// Creates a new [`RomanNumeral`] after checking that `roman` can write
+  /// `value` in numerals.
+  fn new(value: i128, roman: &'a Roman) -> Result<Self, ConversionError> {
+    // Zero can only be a numeral when it's written as nulla.
+    if value == 0 && !roman.writes_nulla() {
+      return Err(ConversionError::ZeroNumeral);
+    }
+
+    if value < 0 && roman.negative_sign.is_none() {
+      return Err(ConversionError::NegativeNumber);
+    }
+
+    // Check the numerals directly, since a number the overflow policy has to
+    // fall back for (like the digits of `OverflowPolicy::Decimal`) isn't a
+    // numeral of the system.
+    let check = &mut CharCounter::default();
+    roman.encode_numerals(value.unsigned_abs(), roman.vinculum, check)?;
+
+    Ok(Self { value, roman })
+  }
+
+  /// Returns the value of the numeral.
+  pub fn value(&self) -> i128 {
+    self.value
+  }
+
+  /// Returns the [`Roman`] the numeral is written in.
+  pub fn roman(&self) -> &'a Roman {
+    self.roman
+  }
+
+  /// Applies a checked operation to the values of two numerals, creating a
+  /// numeral in the system of `self` from the result.
+  fn checked(
+    self,
+    other: Self,
+    operation: fn(i128, i128) -> Option<i128>,
+  ) -> Result<Self, ConversionError> {
+    let value =
+      operation(self.value, other.value).ok_or(ConversionError::Overflow)?;
+    Self::new(value, self.roman)
+  }
+}
+
+impl Roman {
+  /// Creates a [`RomanNumeral`] written in this system, or returns an error
+  /// when the system can't write `value` without its
+  /// [`OverflowPolicy`][crate::OverflowPolicy].
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::Roman;
+  ///
+  /// let custom = Roman::new(&['A', 'B', 'C']);
+  /// assert_eq!(custom.numeral(9).unwrap().to_string(), "AC");
+  /// assert!(custom.numeral(100).is_err());
+  /// ```
+  pub fn numeral<T: num::PrimInt>(
+    &self,
+    value: T,
+  ) -> Result<RomanNumeral<'_>, ConversionError> {
+    let value = value.to_i128().ok_or(ConversionError::Overflow)?;
+    RomanNumeral::new(value, self)
+  }
+
+  /// Parses a [`RomanNumeral`] written in this system like
+  /// [`Roman::from_str`].
+  pub fn numeral_from_str(
+    &self,
+    input: &str,
+  ) -> Result<RomanNumeral<'_>, ConversionError> {
+    RomanNumeral::new(self.from_str(input)?, self)
+  }
+}
+
+impl FromStr for RomanNumeral<'static> {
+  type Err = ConversionError;
+
+  fn from_str(input: &str) -> Result<Self, Self::Err> {
+    default_roman().numeral_from_str(input)
+  }
+}
+
+/// Implements [`TryFrom`] for [`RomanNumeral`] from each integer type.
+macro_rules! impl_try_from {
+  ($($integer:ty),*) => {
+    $(
+      impl TryFrom<$integer> for RomanNumeral<'static> {
+        type Error = ConversionError;
+
+        fn try_from(value: $integer) -> Result<Self, Self::Error> {
+          default_roman().numeral(value)
+        }
+      }
+    )*
+  };
+}
+
+impl_try_from!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl fmt::Display for RomanNumeral<'_> {
+  /// Writes the numeral in its system, or in lowercase with `{:#}`, with the
+  /// width, fill and alignment of the format string applied to it.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let result = self.roman.to_string(self.value).map_err(|_| fmt::Error)?;
+    if f.alternate() {
+      f.pad(&result.to_lowercase())
+    } else {
+      f.pad(&result)
+    }
+  }
+}
+
+impl fmt::Debug for RomanNumeral<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_tuple("RomanNumeral").field(&self.value).finish()
+  }
+}
+
+impl PartialEq for RomanNumeral<'_> {
+  fn eq(&self, other: &Self) -> bool {
+    self.value == other.value
+  }
+}
+
+impl Eq for RomanNumeral<'_> {}
+
+impl PartialOrd for RomanNumeral<'_> {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for RomanNumeral<'_> {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.value.cmp(&other.value)
+  }
+}
+
+impl Hash for RomanNumeral<'_> {
+  /// Hashes only the value, so the lookup table a [`Roman`] builds on first
+  /// use never affects it (clippy's `mutable_key_type` lint can be allowed
+  /// for numerals used as keys).
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.value.hash(state);
+  }
+}
+
+impl<'a> Add for RomanNumeral<'a> {
+  type Output = Result<RomanNumeral<'a>, ConversionError>;
+
+  fn add(self, other: Self) -> Self::Output {
+    self.checked(other, i128::checked_add)
+  }
+}
+
+impl<'a> Sub for RomanNumeral<'a> {
+  type Output = Result<RomanNumeral<'a>, ConversionError>;
+
+  fn sub(self, other: Self) -> Self::Output {
+    self.checked(other, i128::checked_sub)
+  }
+}
+
+impl<'a> Mul for RomanNumeral<'a> {
+  type Output = Result<RomanNumeral<'a>, ConversionError>;
+
+  fn mul(self, other: Self) -> Self::Output {
+    self.checked(other, i128::checked_mul)
+  }
+}
+
+impl<'a> Div for RomanNumeral<'a> {
+  type Output = Result<RomanNumeral<'a>, ConversionError>;
+
+  /// Divides the numerals, rounding towards zero.
+  fn div(self, other: Self) -> Self::Output {
+    self.checked(other, i128::checked_div)
+  }
+}
@@ -0,0 +1,127 @@
+//! Permissive decoding of the irregular subtractive forms found in
+//! inscriptions and older books, like "IIX" for 8 or "IC" for 99.
+
+use alloc::vec::Vec;
+
+use crate::{ConversionError, Roman};
+
+/// An irregular form accepted by [`Roman::from_str_permissive`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Irregularity {
+  /// A character repeated before a larger one and subtracted from it as a
+  /// whole, with the number of times it's repeated (ie. "IIX" or "XXC").
+  RepeatedSubtraction(char, usize, char),
+
+  /// A character subtracted from a larger one that the canonical form doesn't
+  /// subtract it from (ie. "IM", "IC" or "VL").
+  IrregularSubtraction(char, char),
+}
+
+impl Roman {
+  /// Converts a [`str`] to a generic integer [`num::PrimInt`], accepting
+  /// irregular subtractive forms and reporting each one that was applied.
+  ///
+  /// Any run of identical characters that comes before a larger one is
+  /// subtracted from it, so "IIX" is 8, "XXC" is 80, "IM" is 999, "VL" is 45
+  /// and "IC" is 99. Subtractive pairs that are canonical for this [`Roman`]
+  /// (ie. "IV") aren't reported.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::{Irregularity, Roman};
+  ///
+  /// let roman = Roman::default();
+  ///
+  /// let (number, irregularities) =
+  ///   roman.from_str_permissive::<i32>("IIX").unwrap();
+  /// assert_eq!(number, 8);
+  /// assert_eq!(
+  ///   irregularities,
+  ///   [Irregularity::RepeatedSubtraction('I', 2, 'X')]
+  /// );
+  ///
+  /// let (number, irregularities) =
+  ///   roman.from_str_permissive::<i32>("MCMXCIV").unwrap();
+  /// assert_eq!(number, 1994);
+  /// assert!(irregularities.is_empty());
+  /// ```
+  pub fn from_str_permissive<T: num::PrimInt>(
+    &self,
+    input: &str,
+  ) -> Result<(T, Vec<Irregularity>), ConversionError> {
+    let mut irregularities = Vec::new();
+    let result = self.parse_with(input, |input| {
+      self.decode_permissive(input, &mut irregularities)
+    })?;
+
+    Ok((result, irregularities))
+  }
+
+  /// Decodes the symbols in `input`, subtracting every run of identical
+  /// symbols that comes before a larger one and adding any irregular forms to
+  /// `irregularities`.
+  fn decode_permissive(
+    &self,
+    input: &str,
+    irregularities: &mut Vec<Irregularity>,
+  ) -> Result<i128, ConversionError> {
+    let symbols = self.symbols(input)?;
+
+    // Accumulate in an `i128` like `Roman::decode` does.
+    let mut result = 0_i128;
+    let mut start = 0;
+
+    while start < symbols.len() {
+      let symbol = symbols[start];
+
+      // Find the end of the run of symbols with the same value, which can't
+      // include ligatures since they're never subtracted.
+      let mut end = start + 1;
+      while !symbol.ligature
+        && symbols
+          .get(end)
+          .is_some_and(|next| !next.ligature && next.value == symbol.value)
+      {
+        end += 1;
+      }
+
+      let count = end - start;
+      let value = i128::try_from(symbol.value)
+        .ok()
+        .and_then(|value| value.checked_mul(i128::try_from(count).ok()?))
+        .ok_or(ConversionError::Overflow)?;
+
+      let subtract = match symbols.get(end) {
+        Some(next) if !symbol.ligature && symbol.value < next.value => {
+          if count > 1 {
+            irregularities.push(Irregularity::RepeatedSubtraction(
+              symbol.character,
+              count,
+              next.character,
+            ));
+          } else if !self.is_subtractive_pair(symbol.value, next.value) {
+            irregularities.push(Irregularity::IrregularSubtraction(
+              symbol.character,
+              next.character,
+            ));
+          }
+
+          true
+        }
+        _ => false,
+      };
+
+      result = if subtract {
+        result.checked_sub(value)
+      } else {
+        result.checked_add(value)
+      }
+      .ok_or(ConversionError::Overflow)?;
+
+      start = end;
+    }
+
+    Ok(result)
+  }
+}
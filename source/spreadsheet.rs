@@ -0,0 +1,191 @@
+//! The concise forms of the `ROMAN` and `ARABIC` spreadsheet functions, as
+//! implemented by Excel and LibreOffice.
+
+use alloc::{borrow::Cow, string::String};
+
+use crate::{ConversionError, Roman, DEFAULT_CHARACTER_SET};
+
+/// The magnitudes used by the spreadsheet functions from the largest to the
+/// smallest (ie. 'M', 'D', 'C', 'L', 'X', 'V' and 'I').
+const MAGNITUDES: [usize; 7] = [1000, 500, 100, 50, 10, 5, 1];
+
+/// The most concise form of the `ROMAN` function.
+const MAXIMUM_FORM: u8 = 4;
+
+/// The largest number the `ROMAN` function accepts.
+const MAXIMUM_NUMBER: u128 = 3999;
+
+impl Roman {
+  /// Converts a generic integer [`num::PrimInt`] to a [`String`] identical to
+  /// the `ROMAN(number, form)` spreadsheet function.
+  ///
+  /// Form 0 is the classic form and forms 1 through 4 are progressively more
+  /// concise, subtracting from larger characters than the classic form does.
+  /// Only numbers from 0 to 3999 can be converted like in a spreadsheet, with
+  /// 0 written as an empty string.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::Roman;
+  ///
+  /// let roman = Roman::default();
+  /// assert_eq!(roman.to_string_spreadsheet(499, 0).unwrap(), "CDXCIX");
+  /// assert_eq!(roman.to_string_spreadsheet(499, 1).unwrap(), "LDVLIV");
+  /// assert_eq!(roman.to_string_spreadsheet(499, 2).unwrap(), "XDIX");
+  /// assert_eq!(roman.to_string_spreadsheet(499, 3).unwrap(), "VDIV");
+  /// assert_eq!(roman.to_string_spreadsheet(499, 4).unwrap(), "ID");
+  /// ```
+  pub fn to_string_spreadsheet<T: num::PrimInt>(
+    &self,
+    number: T,
+    form: u8,
+  ) -> Result<String, ConversionError> {
+    if form > MAXIMUM_FORM {
+      return Err(ConversionError::InvalidForm(form));
+    }
+
+    if number < T::zero() {
+      return Err(ConversionError::NegativeNumber);
+    }
+
+    let number = number.to_u128().ok_or(ConversionError::GenericConversion)?;
+    if number > MAXIMUM_NUMBER {
+      return Err(ConversionError::Overflow);
+    }
+
+    // Safe to cast since the number is at most 3999.
+    let mut remaining = number as usize;
+    let mut result = String::new();
+
+    // Go through the units ('M', 'C', 'X' and 'I') following the same steps as
+    // the spreadsheet implementations so the output is identical.
+    for index in (0..MAGNITUDES.len()).step_by(2) {
+      let digit = remaining / MAGNITUDES[index];
+
+      if digit % 5 != 4 {
+        if digit > 4 {
+          result.push(self.character_of_magnitude(MAGNITUDES[index - 1])?);
+        }
+
+        let unit = self.character_of_magnitude(MAGNITUDES[index])?;
+        result.extend(core::iter::repeat(unit).take(digit % 5));
+        remaining %= MAGNITUDES[index];
+        continue;
+      }
+
+      // A 4 or 9 is written by subtracting from the next 5 or 10, and each
+      // form step subtracts a smaller character instead for as long as the
+      // rest of the number still fits.
+      let larger = if digit == 4 { index - 1 } else { index - 2 };
+      let mut smaller = index;
+      for _ in 0..form {
+        if smaller == MAGNITUDES.len() - 1
+          || MAGNITUDES[larger] - MAGNITUDES[smaller + 1] > remaining
+        {
+          break;
+        }
+
+        smaller += 1;
+      }
+
+      result.push(self.character_of_magnitude(MAGNITUDES[smaller])?);
+      result.push(self.character_of_magnitude(MAGNITUDES[larger])?);
+      remaining = remaining + MAGNITUDES[smaller] - MAGNITUDES[larger];
+    }
+
+    Ok(result)
+  }
+
+  /// Converts a [`str`] to a generic integer [`num::PrimInt`] like the
+  /// `ARABIC(text)` spreadsheet function.
+  ///
+  /// Every form of [`Roman::to_string_spreadsheet`] is accepted, along with a
+  /// leading "-" for negative numbers. Any character that comes before a
+  /// larger one is subtracted from it, and an empty input is 0 while a "-"
+  /// on its own is an error.
+  ///
+  /// Lowercase characters are accepted with the default character set like
+  /// in a spreadsheet, while custom sets are matched exactly (or with
+  /// [`Roman::with_minuscule`]).
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::Roman;
+  ///
+  /// let roman = Roman::default();
+  /// assert_eq!(roman.from_str_spreadsheet::<i32>("ID").unwrap(), 499);
+  /// assert_eq!(roman.from_str_spreadsheet::<i32>("LDVLIV").unwrap(), 499);
+  /// assert_eq!(roman.from_str_spreadsheet::<i32>("-mcmxcix").unwrap(), -1999);
+  /// ```
+  pub fn from_str_spreadsheet<T: num::PrimInt>(
+    &self,
+    input: &str,
+  ) -> Result<T, ConversionError> {
+    let (negative, input) = match input.strip_prefix('-') {
+      Some(input) => (true, input),
+      None => (false, input),
+    };
+    if negative && input.is_empty() {
+      return Err(ConversionError::MissingNumeral);
+    }
+
+    let symbols = self.symbols(&self.spreadsheet_case(input))?;
+    let mut result = 0_i128;
+
+    for (index, symbol) in symbols.iter().enumerate() {
+      let value =
+        i128::try_from(symbol.value).map_err(|_| ConversionError::Overflow)?;
+      let subtract = symbols
+        .get(index + 1)
+        .is_some_and(|next| symbol.value < next.value);
+
+      result = if subtract {
+        result.checked_sub(value)
+      } else {
+        result.checked_add(value)
+      }
+      .ok_or(ConversionError::Overflow)?;
+    }
+
+    if negative {
+      if T::min_value() == T::zero() && result != 0 {
+        return Err(ConversionError::NegativeNumber);
+      }
+
+      result = -result;
+    }
+
+    T::from(result).ok_or(ConversionError::Overflow)
+  }
+
+  /// Returns `input` in uppercase when the spreadsheet magnitudes use the
+  /// default characters, which spreadsheets read regardless of case.
+  fn spreadsheet_case<'a>(&self, input: &'a str) -> Cow<'a, str> {
+    let default = MAGNITUDES.iter().rev().zip(DEFAULT_CHARACTER_SET).all(
+      |(magnitude, character)| {
+        self.magnitude_character_map.get(magnitude) == Some(&character)
+      },
+    );
+
+    if default {
+      Cow::Owned(input.to_uppercase())
+    } else {
+      Cow::Borrowed(input)
+    }
+  }
+
+  /// Returns the single character for `magnitude` or a
+  /// [`MissingMagnitude`][ConversionError::MissingMagnitude] error.
+  fn character_of_magnitude(
+    &self,
+    magnitude: usize,
+  ) -> Result<char, ConversionError> {
+    self
+      .magnitude_character_map
+      .get(&magnitude)
+      .copied()
+      .ok_or(ConversionError::MissingMagnitude(magnitude))
+  }
+}
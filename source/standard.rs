@@ -0,0 +1,152 @@
+//! Writing numbers in the default Roman numeral system without allocating,
+//! which is also available without the `alloc` feature.
+
+use core::fmt;
+
+use crate::ConversionError;
+
+/// The numerals for every digit of the default Roman numeral system, from the
+/// ones up to the thousands.
+const DIGITS: [[&str; 10]; 4] = [
+  ["", "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX"],
+  ["", "X", "XX", "XXX", "XL", "L", "LX", "LXX", "LXXX", "XC"],
+  ["", "C", "CC", "CCC", "CD", "D", "DC", "DCC", "DCCC", "CM"],
+  ["", "M", "MM", "MMM", "", "", "", "", "", ""],
+];
+
+/// The largest number the default Roman numeral system can write.
+const MAXIMUM: u128 = 3999;
+
+/// The largest magnitude with a character in the default Roman numeral
+/// system.
+const LARGEST_MAGNITUDE: usize = 1000;
+
+/// Writes a generic integer [`num::PrimInt`] in the default Roman numeral
+/// system to `output`, without needing a `Roman` or any allocations.
+///
+/// Numbers are written and rejected the same way as with
+/// `Roman::default().to_string()`, so 0 is written as an empty numeral and
+/// anything above 3999 returns an error.
+///
+/// ## Example
+///
+/// ```rust
+/// use romantic::write_default;
+///
+/// let mut output = String::from("Chapter ");
+/// write_default(&mut output, 12).unwrap();
+/// assert_eq!(output, "Chapter XII");
+///
+/// assert!(write_default(&mut output, 4000).is_err());
+/// ```
+pub fn write_default<W: fmt::Write, T: num::PrimInt>(
+  output: &mut W,
+  number: T,
+) -> Result<(), ConversionError> {
+  if number < T::zero() {
+    return Err(ConversionError::NegativeNumber);
+  }
+
+  let number = number.to_u128().ok_or(ConversionError::GenericConversion)?;
+  if number > MAXIMUM {
+    return Err(overflow_error(number));
+  }
+
+  let mut magnitude = 1000;
+  for digits in DIGITS.iter().rev() {
+    // Safe to cast since the digit is always smaller than 10.
+    output.write_str(digits[(number / magnitude % 10) as usize])?;
+    magnitude /= 10;
+  }
+
+  Ok(())
+}
+
+/// Writes a generic integer [`num::PrimInt`] in the default Roman numeral
+/// system to `buffer` like [`write_default`], returning the part of it that
+/// was written to.
+///
+/// The longest numeral is 15 bytes ("MMMDCCCLXXXVIII"), and a smaller
+/// `buffer` that can't fit the numeral returns a
+/// [`BufferTooSmall`][ConversionError::BufferTooSmall] error.
+///
+/// ## Example
+///
+/// ```rust
+/// use romantic::encode_default;
+///
+/// let mut buffer = [0; 15];
+/// assert_eq!(encode_default(&mut buffer, 2022).unwrap(), "MMXXII");
+/// assert!(encode_default(&mut buffer[..3], 2022).is_err());
+/// ```
+pub fn encode_default<T: num::PrimInt>(
+  buffer: &mut [u8],
+  number: T,
+) -> Result<&str, ConversionError> {
+  let mut writer = SliceWriter { buffer, length: 0 };
+  write_default(&mut writer, number)?;
+
+  let SliceWriter { buffer, length } = writer;
+  let written = buffer
+    .get(..length)
+    .ok_or(ConversionError::BufferTooSmall(length))?;
+
+  // Safe to unwrap since only whole numerals were written.
+  Ok(core::str::from_utf8(written).unwrap())
+}
+
+/// Returns the error `Roman::default().to_string()` returns for a number
+/// above [`MAXIMUM`], which is always for its largest digit.
+fn overflow_error(number: u128) -> ConversionError {
+  let mut magnitude = 1_u128;
+  while number / magnitude >= 10 {
+    magnitude *= 10;
+  }
+
+  let digit = number / magnitude;
+  let Ok(unit) = usize::try_from(magnitude) else {
+    return ConversionError::Overflow;
+  };
+
+  let (factor, subtractive) = match digit {
+    4 => (5, true),
+    9 => (10, true),
+    5.. => (5, false),
+    _ => (1, false),
+  };
+
+  let Some(larger) = unit.checked_mul(factor) else {
+    return ConversionError::Overflow;
+  };
+
+  // Digits right below 5 and 10 subtract their unit from the next step (ie.
+  // 4000 as M before 5000), and the unit only has a character up to the
+  // thousands.
+  if subtractive && unit > LARGEST_MAGNITUDE {
+    return ConversionError::MissingMagnitude(unit);
+  }
+
+  ConversionError::MissingMagnitude(larger)
+}
+
+/// A [`fmt::Write`] output that writes to a byte slice, counting the bytes it
+/// would need once the slice is full.
+struct SliceWriter<'a> {
+  /// The slice to write to.
+  buffer: &'a mut [u8],
+
+  /// The number of bytes written, including the ones that didn't fit.
+  length: usize,
+}
+
+impl fmt::Write for SliceWriter<'_> {
+  fn write_str(&mut self, string: &str) -> fmt::Result {
+    let end = self.length + string.len();
+    if let Some(target) = self.buffer.get_mut(self.length..end) {
+      target.copy_from_slice(string.as_bytes());
+    }
+
+    self.length = end;
+    Ok(())
+  }
+}
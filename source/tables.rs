@@ -0,0 +1,316 @@
+//! The precomputed [`Tables`] a [`Roman`] uses to convert numbers without
+//! looking up each character and digit on the fly.
+
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use core::fmt;
+
+use once_cell::race::OnceBox;
+
+use crate::{CharCounter, Roman};
+
+/// The largest radix digit strings are precomputed for, since every magnitude
+/// needs one entry per digit.
+const MAXIMUM_TABLE_RADIX: usize = 64;
+
+/// The numbers 1 through 12 written one Unicode numeral per character, which
+/// the single character ligatures stand for.
+const LIGATURE_FORMS: [&str; 12] = [
+  "Ⅰ",
+  "ⅠⅠ",
+  "ⅠⅠⅠ",
+  "ⅠⅤ",
+  "Ⅴ",
+  "ⅤⅠ",
+  "ⅤⅠⅠ",
+  "ⅤⅠⅠⅠ",
+  "ⅠⅩ",
+  "Ⅹ",
+  "ⅩⅠ",
+  "ⅩⅠⅠ",
+];
+
+/// The largest number [`Roman::with_lookup_table`] precomputes the numeral
+/// for, the maximum of the default Roman numeral system.
+const LOOKUP_TABLE_LIMIT: u128 = 3999;
+
+/// The tables a [`Roman`] precomputes from its rules, which have to be
+/// rebuilt with [`Roman::update_tables`] whenever the rules change.
+pub(crate) struct Tables {
+  /// The value of each ASCII character in the set indexed by its byte, or 0
+  /// when it isn't in the set.
+  ascii_values: [usize; 128],
+
+  /// Whether [`Tables::ascii_values`] was built, since it's left empty with
+  /// [`Roman::with_tables`] turned off.
+  ascii_table: bool,
+
+  /// Whether every character in the set is ASCII.
+  ascii_set: bool,
+
+  /// The characters and subtractive pairs used to write numbers greedily, see
+  /// [`Roman::greedy_table`].
+  greedy: Vec<(usize, Vec<usize>)>,
+
+  /// The written digits of every magnitude from 1 up, indexed by the exponent
+  /// of the magnitude times the radix plus the digit, or [`None`] when the
+  /// digit can't be written.
+  digits: Vec<Option<String>>,
+
+  /// Whether each of the ligatures for 1 through 12 can be written, which is
+  /// when the rules write the same numerals one per character.
+  ligatures: [bool; 12],
+
+  /// The numerals from 1 up to [`LOOKUP_TABLE_LIMIT`], only built the first
+  /// time they're needed with [`Roman::with_lookup_table`].
+  lookup: OnceBox<Lookup>,
+}
+
+/// The numerals from 1 up to [`LOOKUP_TABLE_LIMIT`] a [`Roman`] precomputes
+/// with [`Roman::with_lookup_table`].
+struct Lookup {
+  /// The numerals written one after the other.
+  numerals: String,
+
+  /// The end of each numeral in [`Lookup::numerals`], starting with the end
+  /// of the empty numeral for 0.
+  ends: Vec<usize>,
+}
+
+impl Lookup {
+  /// Returns the numeral for `number` if the table has it.
+  fn get(&self, number: u128) -> Option<&str> {
+    let number = usize::try_from(number).ok()?;
+    let start = *self.ends.get(number.checked_sub(1)?)?;
+    let end = *self.ends.get(number)?;
+    Some(&self.numerals[start..end])
+  }
+}
+
+impl Default for Tables {
+  fn default() -> Self {
+    Self {
+      ascii_values: [0; 128],
+      ascii_table: false,
+      ascii_set: true,
+      greedy: Vec::new(),
+      digits: Vec::new(),
+      ligatures: [false; 12],
+      lookup: OnceBox::new(),
+    }
+  }
+}
+
+impl fmt::Debug for Tables {
+  /// Leaves out the contents of the tables, which are all derived from the
+  /// rest of the [`Roman`].
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Tables").finish_non_exhaustive()
+  }
+}
+
+impl Tables {
+  /// Returns the value of an ASCII `byte`, or [`None`] when it isn't in the
+  /// set.
+  pub(crate) fn ascii_value(&self, byte: u8) -> Option<usize> {
+    self
+      .ascii_values
+      .get(usize::from(byte))
+      .copied()
+      .filter(|&value| value > 0)
+  }
+
+  /// Returns whether the value of each ASCII byte can be looked up with
+  /// [`Tables::ascii_value`].
+  pub(crate) fn ascii_table(&self) -> bool {
+    self.ascii_table
+  }
+
+  /// Returns whether every character in the set is ASCII.
+  pub(crate) fn ascii_set(&self) -> bool {
+    self.ascii_set
+  }
+
+  /// Returns the characters and subtractive pairs used to write numbers
+  /// greedily.
+  pub(crate) fn greedy(&self) -> &[(usize, Vec<usize>)] {
+    &self.greedy
+  }
+
+  /// Returns the written `digit` of the magnitude `radix^exponent`, or
+  /// [`None`] when it isn't in the table.
+  pub(crate) fn digit(
+    &self,
+    radix: usize,
+    exponent: usize,
+    digit: usize,
+  ) -> Option<&str> {
+    let index = exponent.checked_mul(radix)?.checked_add(digit)?;
+    self.digits.get(index)?.as_deref()
+  }
+
+  /// Returns whether `number` can be written as a single ligature.
+  pub(crate) fn ligature(&self, number: u128) -> bool {
+    let index = usize::try_from(number).ok().and_then(|n| n.checked_sub(1));
+    index.is_some_and(|index| self.ligatures.get(index) == Some(&true))
+  }
+}
+
+impl Roman {
+  /// Sets whether to precompute the numerals for 1 through 3999, which makes
+  /// [`Roman::to_string`] a single lookup for them at the cost of around
+  /// 60 KB of memory. The numerals are computed the first time one of them is
+  /// written, so changing other options afterwards doesn't recompute them.
+  ///
+  /// Only the numbers from 1 up to the first one the character set can't
+  /// write are precomputed, with everything else converted as normal.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::Roman;
+  ///
+  /// let roman = Roman::default().with_lookup_table(true);
+  /// assert_eq!(roman.to_string(1984).unwrap(), "MCMLXXXIV");
+  /// assert!(roman.to_string(4000).is_err());
+  /// ```
+  pub fn with_lookup_table(mut self, lookup_table: bool) -> Self {
+    self.lookup_table = lookup_table;
+    self
+  }
+
+  /// Sets whether to precompute the digit and ASCII tables, which are only
+  /// turned off to compare conversions with and without them.
+  #[doc(hidden)]
+  pub fn with_tables(mut self, tables: bool) -> Self {
+    self.tables_enabled = tables;
+    self.update_tables();
+    self
+  }
+
+  /// Returns the numeral for `number` if the lookup table is enabled and has
+  /// it, building the table first if needed.
+  pub(crate) fn lookup(&self, number: u128) -> Option<&str> {
+    if !self.lookup_table || !(1..=LOOKUP_TABLE_LIMIT).contains(&number) {
+      return None;
+    }
+
+    self
+      .tables
+      .lookup
+      .get_or_init(|| Box::new(self.build_lookup_table()))
+      .get(number)
+  }
+
+  /// Rebuilds the [`Tables`] from the current rules, which has to be done
+  /// after changing any rule that affects how numbers are written or read.
+  pub(crate) fn update_tables(&mut self) {
+    // The tables are used while building them, so start from empty ones to
+    // make sure nothing outdated is used.
+    self.tables = Tables::default();
+
+    for (&character, &value) in &self.character_magnitude_map {
+      if !character.is_ascii() {
+        self.tables.ascii_set = false;
+      } else if self.tables_enabled {
+        self.tables.ascii_values[character as usize] = value;
+      }
+    }
+
+    self.tables.ascii_table = self.tables_enabled;
+    self.tables.greedy = self.build_greedy_table();
+    if self.tables_enabled {
+      self.tables.digits = self.build_digit_table();
+    }
+    if self.unicode_output {
+      self.tables.ligatures = self.build_ligature_table();
+    }
+  }
+
+  /// Returns the entries of the [`Roman::greedy_table`] for the current
+  /// rules.
+  fn build_greedy_table(&self) -> Vec<(usize, Vec<usize>)> {
+    let mut values = self.magnitude_character_map.keys().collect::<Vec<_>>();
+    values.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut table = Vec::new();
+    for &larger in &values {
+      table.push((*larger, vec![*larger]));
+      for &smaller in &values {
+        if self.is_subtractive_pair(*smaller, *larger) {
+          table.push((larger - smaller, vec![*smaller, *larger]));
+        }
+      }
+    }
+
+    table.sort_by_key(|&(value, _)| core::cmp::Reverse(value));
+    table
+  }
+
+  /// Returns the written digits of every magnitude up to the first one
+  /// without any digit that can be written.
+  fn build_digit_table(&self) -> Vec<Option<String>> {
+    let mut table = Vec::new();
+    if self.explicit_values || self.radix > MAXIMUM_TABLE_RADIX {
+      return table;
+    }
+
+    let mut magnitude = Some(1_usize);
+    while let Some(current) = magnitude {
+      let digits = (0..self.radix)
+        .map(|digit| {
+          let mut result = String::new();
+          let written = digit > 0
+            && self.encode_digit(digit, Some(current), &mut result).is_ok();
+          written.then_some(result)
+        })
+        .collect::<Vec<_>>();
+
+      if digits.iter().all(Option::is_none) {
+        break;
+      }
+
+      table.extend(digits);
+      magnitude = current.checked_mul(self.radix);
+    }
+
+    table
+  }
+
+  /// Returns whether the rules write each of the numbers 1 through 12 the
+  /// same as its ligature, so that a ligature never stands for numerals the
+  /// rules don't allow (like "Ⅳ" in additive notation).
+  fn build_ligature_table(&self) -> [bool; 12] {
+    let mut ligatures = [false; 12];
+    for (number, (ligature, form)) in
+      (1..).zip(ligatures.iter_mut().zip(LIGATURE_FORMS))
+    {
+      let mut written = String::new();
+      *ligature =
+        self.encode_digits(number, &mut written).is_ok() && written == form;
+    }
+
+    ligatures
+  }
+
+  /// Returns the numerals from 1 up to the first one the rules can't write,
+  /// at most [`LOOKUP_TABLE_LIMIT`].
+  fn build_lookup_table(&self) -> Lookup {
+    let mut numerals = String::new();
+    let mut ends = vec![0];
+
+    for number in 1..=LOOKUP_TABLE_LIMIT {
+      // Check first so a number that can't be written doesn't leave part of
+      // it behind.
+      let check = &mut CharCounter::default();
+      if self.compute_numerals(number, false, check).is_err() {
+        break;
+      }
+
+      // Safe to unwrap since the number was just checked.
+      self.compute_numerals(number, false, &mut numerals).unwrap();
+      ends.push(numerals.len());
+    }
+
+    Lookup { numerals, ends }
+  }
+}
@@ -0,0 +1,286 @@
+//! Writing numerals to [`fmt::Write`] and [`io::Write`] outputs without
+//! building a [`String`] first, and the [`RomanDisplay`] adapter.
+
+use core::fmt::{self, Alignment, Write};
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::{ConversionError, Roman, VINCULUM};
+
+/// An output that only counts the characters written to it, used to check
+/// whether a number can be written before writing it anywhere else.
+#[derive(Debug, Default)]
+pub(crate) struct CharCounter(pub(crate) usize);
+
+impl Write for CharCounter {
+  fn write_str(&mut self, string: &str) -> fmt::Result {
+    self.0 += string.chars().count();
+    Ok(())
+  }
+}
+
+/// An output that puts a vinculum over every character written to it,
+/// multiplying them by 1000.
+pub(crate) struct Overline<'a>(pub(crate) &'a mut dyn Write);
+
+impl Write for Overline<'_> {
+  fn write_str(&mut self, string: &str) -> fmt::Result {
+    for character in string.chars() {
+      self.0.write_char(character)?;
+
+      // Characters that already have a vinculum only get a second one.
+      if character != VINCULUM {
+        self.0.write_char(VINCULUM)?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+/// An output that applies the minuscule and terminal J styles of a [`Roman`]
+/// to the characters written to it.
+pub(crate) struct Styled<'a> {
+  /// The output the styled characters are written to.
+  output: &'a mut dyn Write,
+
+  /// Whether to write lowercase characters.
+  minuscule: bool,
+
+  /// Whether to write a final "i" as "j".
+  terminal_j: bool,
+
+  /// The last character, held back until it's known whether it's the final
+  /// one when using terminal J.
+  pending: Option<char>,
+}
+
+impl<'a> Styled<'a> {
+  /// Creates a new [`Styled`] output using the styles of `roman`.
+  pub(crate) fn new(roman: &Roman, output: &'a mut dyn Write) -> Self {
+    Self {
+      output,
+      minuscule: roman.minuscule,
+      terminal_j: roman.terminal_j,
+      pending: None,
+    }
+  }
+
+  /// Writes the final character, which has to be called once everything else
+  /// is written.
+  pub(crate) fn finish(self) -> fmt::Result {
+    match self.pending {
+      Some('i') => self.output.write_char('j'),
+      Some('I') => self.output.write_char('J'),
+      Some(character) => self.output.write_char(character),
+      None => Ok(()),
+    }
+  }
+
+  /// Writes a single styled character, holding it back for terminal J.
+  fn write_styled(&mut self, character: char) -> fmt::Result {
+    if !self.terminal_j {
+      return self.output.write_char(character);
+    }
+
+    match self.pending.replace(character) {
+      Some(previous) => self.output.write_char(previous),
+      None => Ok(()),
+    }
+  }
+}
+
+impl Write for Styled<'_> {
+  fn write_str(&mut self, string: &str) -> fmt::Result {
+    if !self.minuscule && !self.terminal_j {
+      return self.output.write_str(string);
+    }
+
+    for character in string.chars() {
+      if self.minuscule {
+        for lowercase in character.to_lowercase() {
+          self.write_styled(lowercase)?;
+        }
+      } else {
+        self.write_styled(character)?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+/// An adapter writing to an [`io::Write`] output that keeps the
+/// [`io::Error`] it fails with.
+#[cfg(feature = "std")]
+struct IoWriter<'a, W> {
+  /// The output to write to.
+  output: &'a mut W,
+
+  /// The error the output failed with, if any.
+  error: Option<io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> Write for IoWriter<'_, W> {
+  fn write_str(&mut self, string: &str) -> fmt::Result {
+    self.output.write_all(string.as_bytes()).map_err(|error| {
+      self.error = Some(error);
+      fmt::Error
+    })
+  }
+}
+
+/// A number that can be formatted with [`fmt::Display`] using a [`Roman`],
+/// created with [`Roman::display`].
+///
+/// The numeral is written straight to the formatter, and the width, fill and
+/// alignment of the format string are applied to it. The number is checked
+/// when the adapter is created, so formatting only fails when the formatter's
+/// output does.
+///
+/// ## Example
+///
+/// ```rust
+/// use romantic::Roman;
+///
+/// let roman = Roman::default();
+/// assert_eq!(format!("{}", roman.display(2022).unwrap()), "MMXXII");
+/// assert_eq!(format!("{:>8}", roman.display(14).unwrap()), "     XIV");
+/// assert_eq!(format!("{:-^7}", roman.display(9).unwrap()), "--IX---");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RomanDisplay<'a, T> {
+  /// The system to write the number in.
+  roman: &'a Roman,
+
+  /// The number to write.
+  number: T,
+
+  /// The number of characters in the numeral.
+  length: usize,
+}
+
+impl<T: num::PrimInt> fmt::Display for RomanDisplay<'_, T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let padding = f.width().unwrap_or(0).saturating_sub(self.length);
+    let (before, after) = match f.align() {
+      None | Some(Alignment::Left) => (0, padding),
+      Some(Alignment::Right) => (padding, 0),
+      Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+      f.write_char(fill)?;
+    }
+
+    // The number was checked when creating the adapter, so any error is the
+    // formatter failing.
+    self
+      .roman
+      .write_number(self.number, f)
+      .map_err(|_| fmt::Error)?;
+
+    for _ in 0..after {
+      f.write_char(fill)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl Roman {
+  /// Writes a generic integer [`num::PrimInt`] to a [`fmt::Write`] output
+  /// like [`Roman::to_string`], without building a [`String`] first.
+  ///
+  /// The number is checked before anything is written, so the output is left
+  /// untouched when an error is returned for it. A failing output returns a
+  /// [`Format`][ConversionError::Format] error.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::Roman;
+  ///
+  /// let roman = Roman::default();
+  /// let mut output = String::from("Year ");
+  /// roman.write_to(&mut output, 2022).unwrap();
+  /// assert_eq!(output, "Year MMXXII");
+  ///
+  /// assert!(roman.write_to(&mut output, 4000).is_err());
+  /// assert_eq!(output, "Year MMXXII");
+  /// ```
+  pub fn write_to<W: Write, T: num::PrimInt>(
+    &self,
+    output: &mut W,
+    number: T,
+  ) -> Result<(), ConversionError> {
+    self.write_number(number, &mut CharCounter::default())?;
+    self.write_number(number, output)?;
+    Ok(())
+  }
+
+  /// Writes a generic integer [`num::PrimInt`] to an [`io::Write`] output
+  /// like [`Roman::write_to`], with a failing output returning an
+  /// [`Io`][ConversionError::Io] error.
+  ///
+  /// The numeral is written in several small pieces, so unbuffered outputs
+  /// should be wrapped in an [`io::BufWriter`].
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::Roman;
+  ///
+  /// let mut output = Vec::new();
+  /// Roman::default().write_to_io(&mut output, 2022).unwrap();
+  /// assert_eq!(output, b"MMXXII");
+  /// ```
+  #[cfg(feature = "std")]
+  pub fn write_to_io<W: io::Write, T: num::PrimInt>(
+    &self,
+    output: &mut W,
+    number: T,
+  ) -> Result<(), ConversionError> {
+    self.write_number(number, &mut CharCounter::default())?;
+
+    let mut writer = IoWriter {
+      output,
+      error: None,
+    };
+    let result = self.write_number(number, &mut writer);
+
+    match writer.error {
+      Some(error) => Err(ConversionError::Io(error)),
+      None => result.map(drop),
+    }
+  }
+
+  /// Creates a [`RomanDisplay`] that formats `number` with this system
+  /// without allocating, or returns the error for a number that can't be
+  /// written.
+  ///
+  /// ## Example
+  ///
+  /// ```rust
+  /// use romantic::Roman;
+  ///
+  /// let roman = Roman::default();
+  /// assert_eq!(roman.display(14).unwrap().to_string(), "XIV");
+  /// assert!(roman.display(4000).is_err());
+  /// ```
+  pub fn display<T: num::PrimInt>(
+    &self,
+    number: T,
+  ) -> Result<RomanDisplay<'_, T>, ConversionError> {
+    let mut counter = CharCounter::default();
+    self.write_number(number, &mut counter)?;
+
+    Ok(RomanDisplay {
+      roman: self,
+      number,
+      length: counter.0,
+    })
+  }
+}
@@ -0,0 +1,44 @@
+use romantic::{ConversionError, Notation, Roman};
+
+use test_case::test_case;
+
+#[test_case(4, "IIII"; "four")]
+#[test_case(9, "VIIII"; "nine")]
+#[test_case(14, "XIIII"; "fourteen")]
+#[test_case(49, "XXXXVIIII"; "forty nine")]
+#[test_case(1999, "MDCCCCLXXXXVIIII"; "nineteen ninety nine")]
+#[test_case(4999, "MMMMDCCCCLXXXXVIIII"; "maximum")]
+fn test_to_string(input: i32, expected: &str) {
+  let roman = Roman::default().with_notation(Notation::Additive);
+  assert_eq!(roman.to_string(input).unwrap(), expected);
+  assert_eq!(roman.from_str_strict::<i32>(expected).unwrap(), input);
+}
+
+#[test_case(4, "AAAA"; "four")]
+#[test_case(9, "BAAAA"; "nine")]
+fn test_custom_to_string(input: i32, expected: &str) {
+  let custom = Roman::new(&['A', 'B']).with_notation(Notation::Additive);
+  assert_eq!(custom.to_string(input).unwrap(), expected);
+  assert_eq!(custom.from_str_strict::<i32>(expected).unwrap(), input);
+}
+
+#[test]
+fn test_from_str_accepts_subtractive() {
+  let roman = Roman::default().with_notation(Notation::Additive);
+  assert_eq!(roman.from_str::<i32>("XIV").unwrap(), 14);
+}
+
+#[test]
+fn test_strict_errors() {
+  let roman = Roman::default().with_notation(Notation::Additive);
+
+  assert!(matches!(
+    roman.from_str_strict::<i32>("IV"),
+    Err(ConversionError::InvalidSubtraction('I', 'V'))
+  ));
+  assert!(matches!(
+    roman.from_str_strict::<i32>("IIIII"),
+    Err(ConversionError::InvalidRepetition('I'))
+  ));
+  assert!(roman.to_string(5000).is_err());
+}
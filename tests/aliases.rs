@@ -0,0 +1,59 @@
+use romantic::{ConstructionError, ConversionError, Roman};
+
+use test_case::test_case;
+
+/// Creates the default numeral system with lowercase, Unicode and fullwidth
+/// aliases.
+fn aliased() -> Roman {
+  Roman::new_with_aliases(&[
+    ('I', &['i', 'Ⅰ', 'Ｉ']),
+    ('V', &['v', 'Ⅴ', 'Ｖ']),
+    ('X', &['x', 'Ⅹ', 'Ｘ']),
+    ('L', &['l', 'Ⅼ', 'Ｌ']),
+    ('C', &['c', 'Ⅽ', 'Ｃ']),
+    ('D', &['d', 'Ⅾ', 'Ｄ']),
+    ('M', &['m', 'Ⅿ', 'Ｍ']),
+  ])
+  .unwrap()
+}
+
+#[test_case("MMXXII", 2022; "primary")]
+#[test_case("mmxxii", 2022; "lowercase")]
+#[test_case("ⅯⅯⅩⅩⅠⅠ", 2022; "unicode")]
+#[test_case("ＭＭＸＸＩＩ", 2022; "fullwidth")]
+#[test_case("MＭⅩxＩi", 2022; "mixed")]
+#[test_case("xＩv", 14; "mixed subtraction")]
+fn test_from_str(input: &str, expected: i32) {
+  let roman = aliased();
+  assert_eq!(roman.from_str::<i32>(input).unwrap(), expected);
+  assert_eq!(roman.from_str_strict::<i32>(input).unwrap(), expected);
+}
+
+#[test]
+fn test_to_string() {
+  assert_eq!(aliased().to_string(2022).unwrap(), "MMXXII");
+}
+
+#[test]
+fn test_same_value_duplicates() {
+  let roman = Roman::new_with_aliases(&[('I', &['I', 'i', 'i'])]).unwrap();
+  assert_eq!(roman.from_str::<i32>("Ii").unwrap(), 2);
+}
+
+#[test_case(&[('I', &['V']), ('V', &[])], 'V'; "alias is another primary")]
+#[test_case(&[('I', &['a']), ('V', &['a'])], 'a'; "shared alias")]
+#[test_case(&[('I', &[]), ('I', &[])], 'I'; "duplicate primary")]
+fn test_collisions(character_set: &[(char, &[char])], expected: char) {
+  assert!(matches!(
+    Roman::new_with_aliases(character_set),
+    Err(ConstructionError::DuplicateCharacter(character)) if character == expected
+  ));
+}
+
+#[test]
+fn test_strict_repetition() {
+  assert!(matches!(
+    aliased().from_str_strict::<i32>("IiＩⅠ"),
+    Err(ConversionError::InvalidRepetition(_))
+  ));
+}
@@ -0,0 +1,60 @@
+use romantic::{ConversionError, Roman};
+
+use test_case::test_case;
+
+#[test_case(400, "CD"; "below apostrophus")]
+#[test_case(500, "D"; "five hundred")]
+#[test_case(1400, "CIↃCD"; "fourteen hundred")]
+#[test_case(900, "CM"; "nine hundred")]
+#[test_case(1900, "CIↃCM"; "nineteen hundred")]
+#[test_case(1000, "CIↃ"; "one thousand")]
+#[test_case(1100, "CIↃC"; "eleven hundred")]
+#[test_case(4000, "CIↃIↃↃ"; "four thousand")]
+#[test_case(5000, "IↃↃ"; "five thousand")]
+#[test_case(10_000, "CCIↃↃ"; "ten thousand")]
+#[test_case(11_000, "CCIↃↃCIↃ"; "eleven thousand")]
+#[test_case(50_000, "IↃↃↃ"; "fifty thousand")]
+#[test_case(100_000, "CCCIↃↃↃ"; "hundred thousand")]
+#[test_case(1666, "CIↃDCLXVI"; "mixed")]
+fn test_round_trip(input: u32, expected: &str) {
+  let roman = Roman::default().with_apostrophus(true);
+  assert_eq!(roman.to_string(input).unwrap(), expected);
+  assert_eq!(roman.from_str::<u32>(expected).unwrap(), input);
+  assert_eq!(roman.from_str_strict::<u32>(expected).unwrap(), input);
+}
+
+#[test_case("IↃ", 500; "five hundred")]
+#[test_case("CIↃIↃ", 1500; "fifteen hundred")]
+#[test_case("MIↃ", 1500; "regular thousand")]
+#[test_case("CIↃD", 1500; "regular five hundred")]
+#[test_case("MDCCIↃↃ", 11_500; "mixed ten thousand")]
+fn test_from_str_mixed(input: &str, expected: u32) {
+  let roman = Roman::default().with_apostrophus(true);
+  assert_eq!(roman.from_str::<u32>(input).unwrap(), expected);
+}
+
+#[test_case(1005, "EAↃB"; "thousand")]
+#[test_case(505, "AↃB"; "five hundred")]
+fn test_custom(input: i32, expected: &str) {
+  let custom = Roman::new(&['A', 'B', 'C', 'D', 'E']).with_apostrophus(true);
+  assert_eq!(custom.to_string(input).unwrap(), expected);
+  assert_eq!(custom.from_str::<i32>(expected).unwrap(), input);
+}
+
+#[test_case("CIↃↃ"; "unbalanced")]
+#[test_case("Ↄ"; "lone reversed c")]
+fn test_errors(input: &str) {
+  let roman = Roman::default().with_apostrophus(true);
+  assert!(matches!(
+    roman.from_str::<i32>(input),
+    Err(ConversionError::InvalidCharacter('Ↄ'))
+  ));
+}
+
+#[test]
+fn test_disabled() {
+  assert!(matches!(
+    Roman::default().from_str::<i32>("CIↃ"),
+    Err(ConversionError::InvalidCharacter('Ↄ'))
+  ));
+}
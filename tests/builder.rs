@@ -0,0 +1,109 @@
+use romantic::{ConstructionError, ConversionError, RomanBuilder};
+
+use test_case::test_case;
+
+#[test_case(4, "IV"; "four")]
+#[test_case(9, "IX"; "nine")]
+#[test_case(3999, "MMMCMXCIX"; "maximum")]
+fn test_default_rules(input: i32, expected: &str) {
+  let roman = RomanBuilder::default().build().unwrap();
+  assert_eq!(roman.to_string(input).unwrap(), expected);
+  assert_eq!(roman.from_str_strict::<i32>(expected).unwrap(), input);
+}
+
+#[test_case(4, "IIII"; "four")]
+#[test_case(9, "IX"; "nine")]
+#[test_case(40, "XXXX"; "forty")]
+#[test_case(90, "XC"; "ninety")]
+#[test_case(4000, "MMMM"; "four thousand")]
+fn test_clock_rules(input: i32, expected: &str) {
+  let clock = RomanBuilder::default()
+    .subtractive_pairs(&[('I', 'X'), ('X', 'C'), ('C', 'M')])
+    .maximum_repetitions(4)
+    .build()
+    .unwrap();
+  assert_eq!(clock.to_string(input).unwrap(), expected);
+  assert_eq!(clock.from_str_strict::<i32>(expected).unwrap(), input);
+}
+
+#[test]
+fn test_without_subtraction() {
+  let roman = RomanBuilder::default().subtraction(false).build().unwrap();
+  assert_eq!(roman.to_string(49).unwrap(), "XXXXVIIII");
+  assert_eq!(roman.from_str::<i32>("IV").unwrap(), 6);
+  assert!(matches!(
+    roman.from_str_strict::<i32>("IV"),
+    Err(ConversionError::InvalidSubtraction('I', 'V'))
+  ));
+}
+
+#[test]
+fn test_maximum_repetitions() {
+  let roman = RomanBuilder::default()
+    .maximum_repetitions(2)
+    .build()
+    .unwrap();
+  assert_eq!(roman.to_string(2222).unwrap(), "MMCCXXII");
+  assert!(matches!(
+    roman.to_string(3),
+    Err(ConversionError::InvalidRepetition('I'))
+  ));
+  assert!(matches!(
+    roman.from_str_strict::<i32>("XXX"),
+    Err(ConversionError::InvalidRepetition('X'))
+  ));
+}
+
+#[test]
+fn test_repeatable() {
+  let roman = RomanBuilder::default()
+    .repeatable(&['I', 'X', 'C'])
+    .build()
+    .unwrap();
+  assert_eq!(roman.to_string(1333).unwrap(), "MCCCXXXIII");
+  assert!(matches!(
+    roman.to_string(2000),
+    Err(ConversionError::InvalidRepetition('M'))
+  ));
+  assert!(matches!(
+    roman.from_str_strict::<i32>("MM"),
+    Err(ConversionError::InvalidRepetition('M'))
+  ));
+}
+
+#[test]
+fn test_pairs_limit_from_str() {
+  let roman = RomanBuilder::default()
+    .subtractive_pairs(&[('I', 'V')])
+    .build()
+    .unwrap();
+  assert_eq!(roman.from_str::<i32>("IV").unwrap(), 4);
+  assert_eq!(roman.from_str::<i32>("IX").unwrap(), 11);
+  assert!(matches!(
+    roman.to_string(9),
+    Err(ConversionError::InvalidRepetition('I'))
+  ));
+}
+
+#[test]
+fn test_custom_set() {
+  let custom = RomanBuilder::new(&['A', 'B', 'C'])
+    .subtractive_pairs(&[('A', 'C')])
+    .maximum_repetitions(4)
+    .build()
+    .unwrap();
+  assert_eq!(custom.to_string(4).unwrap(), "AAAA");
+  assert_eq!(custom.to_string(9).unwrap(), "AC");
+}
+
+#[test_case(RomanBuilder::default().subtractive_pairs(&[('I', 'Z')]), ConstructionError::UnknownCharacter('Z'); "unknown pair")]
+#[test_case(RomanBuilder::default().repeatable(&['Z']), ConstructionError::UnknownCharacter('Z'); "unknown repeatable")]
+#[test_case(RomanBuilder::default().subtractive_pairs(&[('V', 'X')]), ConstructionError::InvalidSubtractivePair('V', 'X'); "five from ten")]
+#[test_case(RomanBuilder::default().subtractive_pairs(&[('I', 'C')]), ConstructionError::InvalidSubtractivePair('I', 'C'); "one from hundred")]
+#[test_case(RomanBuilder::new(&['A', 'A']), ConstructionError::DuplicateCharacter('A'); "duplicate")]
+fn test_errors(builder: RomanBuilder, expected: ConstructionError) {
+  assert_eq!(
+    builder.build().unwrap_err().to_string(),
+    expected.to_string()
+  );
+}
@@ -0,0 +1,99 @@
+use romantic::{ConversionError, Roman};
+
+use test_case::test_case;
+
+#[test]
+fn test_from_str_equivalence() {
+  let roman = Roman::default();
+  for number in 1..=3999 {
+    let numeral = roman.to_string(number).unwrap();
+    assert_eq!(roman.from_bytes::<u16>(numeral.as_bytes()).unwrap(), number);
+  }
+}
+
+#[test_case(b"MMXXII", 2022; "default")]
+#[test_case(b"", 0; "empty")]
+#[test_case(b"IIII", 4; "non canonical")]
+fn test_from_bytes(input: &[u8], expected: u16) {
+  assert_eq!(Roman::default().from_bytes::<u16>(input).unwrap(), expected);
+}
+
+#[test_case(b"MMXZII", 3; "invalid character")]
+#[test_case(b"MM\xffI", 2; "invalid byte")]
+#[test_case(b"mmxxii", 0; "lowercase")]
+fn test_invalid_byte(input: &[u8], offset: usize) {
+  assert!(matches!(
+    Roman::default().from_bytes::<u16>(input),
+    Err(ConversionError::InvalidByte(position)) if position == offset
+  ));
+}
+
+#[test]
+fn test_signed() {
+  let roman = Roman::default()
+    .with_negative_sign(Some("−"))
+    .with_nulla(true);
+  assert_eq!(roman.from_bytes::<i32>("−XIV".as_bytes()).unwrap(), -14);
+  assert_eq!(roman.from_bytes::<i32>(b"N").unwrap(), 0);
+  assert!(matches!(
+    roman.from_bytes::<i32>("−XIZ".as_bytes()),
+    Err(ConversionError::InvalidByte(5))
+  ));
+  assert!(matches!(
+    roman.from_bytes::<u32>("−XIV".as_bytes()),
+    Err(ConversionError::NegativeNumber)
+  ));
+  assert!(matches!(
+    roman.from_bytes::<u8>(b"CCC"),
+    Err(ConversionError::Overflow)
+  ));
+  assert!(matches!(
+    roman.from_bytes::<i32>("−".as_bytes()),
+    Err(ConversionError::MissingNumeral)
+  ));
+}
+
+#[test_case("Ðé", Ok(6); "valid")]
+#[test_case("ÐI", Err(2); "invalid character")]
+#[test_case("éÐüZ", Err(6); "invalid after non ascii")]
+fn test_non_ascii_set(input: &str, expected: Result<i32, usize>) {
+  let roman = Roman::new(&['é', 'Ð', 'ü']);
+  let result = roman.from_bytes::<i32>(input.as_bytes());
+  match expected {
+    Ok(expected) => assert_eq!(result.unwrap(), expected),
+    Err(offset) => assert!(matches!(
+      result,
+      Err(ConversionError::InvalidByte(position)) if position == offset
+    )),
+  }
+}
+
+#[test]
+fn test_invalid_utf8() {
+  let roman = Roman::new(&['é', 'Ð', 'ü']);
+  assert!(matches!(
+    roman.from_bytes::<i32>(b"\xc3\x90\xff"),
+    Err(ConversionError::InvalidByte(2))
+  ));
+}
+
+#[test]
+fn test_options() {
+  let roman = Roman::default()
+    .with_vinculum(true)
+    .with_minuscule(true)
+    .with_unicode_input(true);
+  assert_eq!(
+    roman.from_bytes::<i32>("v\u{305}i".as_bytes()).unwrap(),
+    5001
+  );
+  assert_eq!(roman.from_bytes::<i32>("ⅯⅯⅩⅩⅡ".as_bytes()).unwrap(), 2022);
+  assert!(matches!(
+    roman.from_bytes::<i32>("\u{305}V".as_bytes()),
+    Err(ConversionError::InvalidByte(0))
+  ));
+  assert!(matches!(
+    roman.from_bytes::<i32>("ⅯⅯz".as_bytes()),
+    Err(ConversionError::InvalidByte(6))
+  ));
+}
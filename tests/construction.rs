@@ -0,0 +1,53 @@
+use romantic::{ConstructionError, Roman};
+
+use test_case::test_case;
+
+#[test_case(&['A', 'B', 'A'], 'A'; "duplicate unit")]
+#[test_case(&['I', 'V', 'X', 'V'], 'V'; "duplicate five")]
+fn test_duplicate_character(character_set: &[char], expected: char) {
+  assert!(matches!(
+    Roman::try_new(character_set),
+    Err(ConstructionError::DuplicateCharacter(character)) if character == expected
+  ));
+}
+
+#[test]
+fn test_empty_character_set() {
+  assert!(matches!(
+    Roman::try_new(&[]),
+    Err(ConstructionError::EmptyCharacterSet)
+  ));
+  assert!(matches!(
+    Roman::new_with_aliases(&[]),
+    Err(ConstructionError::EmptyCharacterSet)
+  ));
+}
+
+#[test]
+fn test_magnitude_overflow() {
+  // Every 2 characters multiply the magnitude by 10, so 256 characters are
+  // far more than a `usize` can hold.
+  let character_set = ('\u{E000}'..='\u{E0FF}').collect::<Vec<_>>();
+  let overflow = Roman::try_new(&character_set);
+
+  let expected = (0..)
+    .map(|exponent| 10_usize.checked_pow(exponent))
+    .take_while(Option::is_some)
+    .count();
+  assert!(matches!(
+    overflow,
+    Err(ConstructionError::MagnitudeOverflow(character))
+      if character == character_set[expected * 2 - 1]
+  ));
+
+  // The infallible constructor leaves the overflowing characters out.
+  let roman = Roman::new(&character_set);
+  assert_eq!(roman.to_string(1).unwrap(), "\u{E000}");
+}
+
+#[test]
+fn test_valid() {
+  let roman = Roman::try_new(&['I', 'V', 'X', 'L', 'C', 'D', 'M']).unwrap();
+  assert_eq!(roman.to_string(2022).unwrap(), "MMXXII");
+  assert_eq!(roman.from_str::<i32>("MMXXII").unwrap(), 2022);
+}
@@ -0,0 +1,126 @@
+use romantic::{CounterStyle, CounterStyleError, RomanCounter};
+
+use test_case::test_case;
+
+#[test_case("decimal", 2023, "2023"; "decimal")]
+#[test_case("decimal", -7, "-7"; "decimal negative")]
+#[test_case("decimal-leading-zero", 5, "05"; "leading zero")]
+#[test_case("decimal-leading-zero", -5, "-5"; "leading zero negative")]
+#[test_case("decimal-leading-zero", 123, "123"; "leading zero long")]
+#[test_case("cjk-decimal", 2023, "二〇二三"; "cjk decimal")]
+#[test_case("cjk-decimal", -5, "-5"; "cjk decimal negative")]
+#[test_case("lower-alpha", 26, "z"; "lower alpha")]
+#[test_case("lower-alpha", 27, "aa"; "lower alpha wrap")]
+#[test_case("upper-latin", 702, "ZZ"; "upper latin")]
+#[test_case("lower-alpha", 0, "0"; "lower alpha zero")]
+#[test_case("lower-alpha", -1, "-1"; "lower alpha negative")]
+#[test_case("lower-greek", 24, "ω"; "lower greek")]
+#[test_case("lower-greek", 25, "αα"; "lower greek wrap")]
+#[test_case("upper-roman", 1994, "MCMXCIV"; "upper roman")]
+#[test_case("lower-roman", 1994, "mcmxciv"; "lower roman")]
+#[test_case("upper-roman", 0, "0"; "upper roman zero")]
+#[test_case("armenian", 2023, "ՍԻԳ"; "armenian")]
+#[test_case("lower-armenian", 9999, "քջղթ"; "lower armenian")]
+#[test_case("armenian", 10_000, "10000"; "armenian range")]
+#[test_case("georgian", 2023, "ცკგ"; "georgian")]
+#[test_case("georgian", 19_999, "ჵჰშჟთ"; "georgian range end")]
+#[test_case("hebrew", 15, "טו"; "hebrew fifteen")]
+#[test_case("hebrew", 16, "טז"; "hebrew sixteen")]
+#[test_case("hebrew", 1000, "א׳"; "hebrew thousand")]
+#[test_case("disc", 3, "•"; "disc")]
+#[test_case("square", -3, "▪"; "square negative")]
+fn test_predefined(name: &str, input: i64, expected: &str) {
+  let style = CounterStyle::predefined(name).unwrap();
+  assert_eq!(style.name(), name);
+  assert_eq!(style.format(input), expected);
+}
+
+#[test_case("decimal", 1, "1. "; "decimal")]
+#[test_case("cjk-decimal", 1, "一、"; "cjk decimal")]
+#[test_case("cjk-decimal", -1, "-1、"; "cjk decimal fallback")]
+#[test_case("circle", 1, "◦ "; "circle")]
+fn test_marker(name: &str, input: i64, expected: &str) {
+  let style = CounterStyle::predefined(name).unwrap();
+  assert_eq!(style.format_marker(input), expected);
+}
+
+#[test]
+fn test_roman_counter() {
+  let upper = CounterStyle::predefined("upper-roman").unwrap();
+  let lower = CounterStyle::predefined("lower-roman").unwrap();
+  for value in -100..=5000 {
+    assert_eq!(
+      upper.format(value),
+      RomanCounter::upper_roman().format(value)
+    );
+    assert_eq!(
+      lower.format(value),
+      RomanCounter::lower_roman().format(value)
+    );
+  }
+}
+
+#[test_case("system: cyclic; symbols: a b c;", &[(1, "a"), (3, "c"), (4, "a"), (0, "c"), (-1, "b")]; "cyclic")]
+#[test_case("system: fixed 3; symbols: a b;", &[(3, "a"), (4, "b"), (5, "5"), (2, "2")]; "fixed")]
+#[test_case("system: fixed; symbols: a b;", &[(1, "a"), (2, "b"), (3, "3")]; "fixed default")]
+#[test_case("system: symbolic; symbols: '*' †;", &[(1, "*"), (2, "†"), (3, "**"), (4, "††"), (0, "0")]; "symbolic")]
+#[test_case("system: symbolic; symbols: x;", &[(150, &"x".repeat(150)), (151, "151")]; "symbolic limit")]
+#[test_case("system: alphabetic; symbols: a b;", &[(1, "a"), (2, "b"), (3, "aa"), (6, "bb"), (7, "aaa")]; "alphabetic")]
+#[test_case("system: numeric; symbols: '0' '1';", &[(0, "0"), (5, "101"), (-5, "-101")]; "numeric")]
+#[test_case("system: additive; additive-symbols: 5 V, 1 I, 0 N;", &[(0, "N"), (7, "VII"), (-2, "-2")]; "additive")]
+#[test_case("system: additive; additive-symbols: 5 V;", &[(0, "0"), (7, "7"), (10, "VV")]; "additive unreachable")]
+#[test_case("system: additive; additive-symbols: 1 I;", &[(150, &"I".repeat(150)), (151, "151")]; "additive limit")]
+#[test_case("system: numeric; symbols: a b; range: 1 3, 6 infinite;", &[(3, "bb"), (4, "4"), (6, "bba")]; "range")]
+#[test_case("system: numeric; symbols: a b; range: infinite 0;", &[(0, "a"), (1, "1")]; "infinite range")]
+#[test_case("system: fixed; symbols: a b; fallback: upper-roman;", &[(2, "b"), (5, "V"), (4000, "4000")]; "fallback")]
+#[test_case("system: fixed; symbols: a; fallback: unknown;", &[(2, "2")]; "unknown fallback")]
+#[test_case("system: numeric; symbols: '0' '1'; negative: '(' ')';", &[(-2, "(10)")]; "negative")]
+#[test_case("system: numeric; symbols: '0' '1'; pad: '0' 4; negative: '(' ')';", &[(-1, "(01)"), (1, "0001")]; "pad negative")]
+#[test_case(r"system: cyclic; symbols: \41 '\42' \1F600  x;", &[(1, "A"), (2, "B"), (3, "😀"), (4, "x")]; "escapes")]
+#[test_case("system: cyclic; /* comment */ symbols: a; speak-as: auto", &[(1, "a")]; "comment")]
+fn test_custom(descriptors: &str, expected: &[(i64, &str)]) {
+  let rule = format!("@counter-style custom {{ {descriptors} }}");
+  let style = CounterStyle::parse(&rule).unwrap();
+  for &(input, expected) in expected {
+    assert_eq!(style.format(input), expected, "{input}");
+  }
+}
+
+#[test]
+fn test_prefix_suffix() {
+  let style = CounterStyle::parse(
+    "@counter-style paren { system: alphabetic; symbols: a b; prefix: '('; \
+     suffix: ') '; }",
+  )
+  .unwrap();
+  assert_eq!(style.format_marker(3), "(aa) ");
+  assert_eq!(style.format_marker(-3), "(-3) ");
+}
+
+#[test]
+fn test_extremes() {
+  let style = CounterStyle::predefined("upper-roman").unwrap();
+  assert_eq!(style.format(i128::MIN), i128::MIN.to_string());
+  assert_eq!(style.format(u128::MAX), u128::MAX.to_string());
+  assert_eq!(style.format(255_u8), "CCLV");
+}
+
+#[test_case("@counter-style a { symbols: x; unknown: 1; }", CounterStyleError::UnknownDescriptor("unknown".into()); "unknown descriptor")]
+#[test_case("@counter-style a { system: extends decimal; }", CounterStyleError::InvalidDescriptor("system".into()); "extends")]
+#[test_case("@counter-style a { system: additive; additive-symbols: 1 I, 5 V; }", CounterStyleError::InvalidDescriptor("additive-symbols".into()); "ascending weights")]
+#[test_case("@counter-style a { system: cyclic; symbols: a; range: 5 1; }", CounterStyleError::InvalidDescriptor("range".into()); "reversed range")]
+#[test_case("@counter-style a { system: cyclic; symbols: a; negative: a b c; }", CounterStyleError::InvalidDescriptor("negative".into()); "negative")]
+#[test_case("@counter-style a { system: cyclic; symbols: a; pad: -1 x; }", CounterStyleError::InvalidDescriptor("pad".into()); "pad")]
+#[test_case("@counter-style a { system: numeric; symbols: a; }", CounterStyleError::MissingSymbols("a".into()); "numeric symbols")]
+#[test_case("@counter-style a { system: additive; }", CounterStyleError::MissingSymbols("a".into()); "additive symbols")]
+#[test_case("@counter-style a { symbols: a", CounterStyleError::InvalidSyntax; "unclosed")]
+#[test_case("@counter-style { symbols: a; }", CounterStyleError::InvalidSyntax; "no name")]
+#[test_case("@media a { symbols: a; }", CounterStyleError::InvalidSyntax; "wrong rule")]
+#[test_case("@counter-style a { symbols: 'a; }", CounterStyleError::InvalidSyntax; "unclosed string")]
+#[test_case("@counter-style a { symbols: a; } b", CounterStyleError::InvalidSyntax; "trailing")]
+fn test_errors(input: &str, expected: CounterStyleError) {
+  assert_eq!(
+    CounterStyle::parse(input).unwrap_err().to_string(),
+    expected.to_string()
+  );
+}
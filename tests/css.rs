@@ -0,0 +1,27 @@
+use romantic::RomanCounter;
+
+use test_case::test_case;
+
+#[test_case(1, "I", "i"; "one")]
+#[test_case(4, "IV", "iv"; "four")]
+#[test_case(1994, "MCMXCIV", "mcmxciv"; "nineteen ninety four")]
+#[test_case(3999, "MMMCMXCIX", "mmmcmxcix"; "range end")]
+#[test_case(4000, "4000", "4000"; "after range")]
+#[test_case(0, "0", "0"; "zero")]
+#[test_case(-1, "-1", "-1"; "negative one")]
+#[test_case(-3999, "-3999", "-3999"; "negative range end")]
+#[test_case(i64::MIN, "-9223372036854775808", "-9223372036854775808"; "minimum")]
+fn test_format(input: i64, upper: &str, lower: &str) {
+  assert_eq!(RomanCounter::upper_roman().format(input), upper);
+  assert_eq!(RomanCounter::lower_roman().format(input), lower);
+}
+
+#[test]
+fn test_small_types() {
+  assert_eq!(RomanCounter::upper_roman().format(255_u8), "CCLV");
+  assert_eq!(RomanCounter::upper_roman().format(-128_i8), "-128");
+  assert_eq!(
+    RomanCounter::upper_roman().format(u128::MAX),
+    u128::MAX.to_string()
+  );
+}
@@ -0,0 +1,53 @@
+use romantic::{ConversionError, FromRoman, Notation, Roman, ToRoman};
+
+use test_case::test_case;
+
+#[test_case(1, "I"; "one")]
+#[test_case(14, "XIV"; "fourteen")]
+#[test_case(2022, "MMXXII"; "twenty twenty two")]
+#[test_case(3999, "MMMCMXCIX"; "maximum")]
+fn test_round_trip(number: u16, numeral: &str) {
+  assert_eq!(number.to_roman().unwrap(), numeral);
+  assert_eq!(numeral.parse_roman::<u16>().unwrap(), number);
+  assert_eq!(numeral.to_string().parse_roman::<u16>().unwrap(), number);
+}
+
+#[test]
+fn test_integer_types() {
+  assert_eq!(9_u8.to_roman().unwrap(), "IX");
+  assert_eq!(9_i8.to_roman().unwrap(), "IX");
+  assert_eq!(9_u128.to_roman().unwrap(), "IX");
+  assert_eq!(9_isize.to_roman().unwrap(), "IX");
+  assert_eq!("IX".parse_roman::<i128>().unwrap(), 9);
+}
+
+#[test]
+fn test_custom() {
+  let additive = Roman::default().with_notation(Notation::Additive);
+  assert_eq!(4.to_roman_with(&additive).unwrap(), "IIII");
+  assert_eq!("IIII".parse_roman_with::<u32>(&additive).unwrap(), 4);
+
+  let owned = String::from("AC");
+  let custom = Roman::new(&['A', 'B', 'C']);
+  assert_eq!(owned.parse_roman_with::<u32>(&custom).unwrap(), 9);
+}
+
+#[test]
+fn test_errors() {
+  assert!(matches!(
+    4000.to_roman(),
+    Err(ConversionError::MissingMagnitude(_))
+  ));
+  assert!(matches!(
+    (-1).to_roman(),
+    Err(ConversionError::NegativeNumber)
+  ));
+  assert!(matches!(
+    "XIZ".parse_roman::<u32>(),
+    Err(ConversionError::InvalidCharacter('Z'))
+  ));
+  assert!(matches!(
+    "CCC".parse_roman::<u8>(),
+    Err(ConversionError::Overflow)
+  ));
+}
@@ -0,0 +1,85 @@
+use num::rational::Ratio;
+use romantic::{ConversionError, Roman};
+
+use test_case::test_case;
+
+#[test_case((0, 1), ""; "zero")]
+#[test_case((1, 12), "·"; "one twelfth")]
+#[test_case((1, 6), ":"; "two twelfths")]
+#[test_case((1, 4), "∴"; "three twelfths")]
+#[test_case((1, 3), "∷"; "four twelfths")]
+#[test_case((5, 12), "⁙"; "five twelfths")]
+#[test_case((1, 2), "S"; "half")]
+#[test_case((11, 12), "S⁙"; "eleven twelfths")]
+#[test_case((15, 2), "VIIS"; "seven and a half")]
+#[test_case((3, 1), "III"; "whole number")]
+#[test_case((47999, 12), "MMMCMXCIXS⁙"; "maximum")]
+fn test_round_trip(input: (i32, i32), expected: &str) {
+  let roman = Roman::default();
+  let input = Ratio::new(input.0, input.1);
+  assert_eq!(roman.to_string_fraction(input).unwrap(), expected);
+  assert_eq!(roman.from_str_fraction::<i32>(expected).unwrap(), input);
+}
+
+#[test_case("··", (1, 6); "repeated dots")]
+#[test_case("S·····", (11, 12); "repeated dots after half")]
+#[test_case("X\u{10190}", (61, 6); "sextans sign")]
+#[test_case("\u{10191}", (1, 12); "uncia sign")]
+#[test_case("\u{10192}", (1, 24); "semuncia sign")]
+#[test_case("\u{10193}", (1, 72); "sextula sign")]
+#[test_case("\u{10194}", (1, 144); "dimidia sextula sign")]
+#[test_case("\u{10195}", (1, 1728); "siliqua sign")]
+fn test_from_str_fraction(input: &str, expected: (i32, i32)) {
+  assert_eq!(
+    Roman::default().from_str_fraction::<i32>(input).unwrap(),
+    Ratio::new(expected.0, expected.1)
+  );
+}
+
+#[test]
+fn test_small_types() {
+  let roman = Roman::default();
+  assert_eq!(roman.from_str_fraction::<u8>("I").unwrap(), Ratio::from(1));
+  assert_eq!(
+    roman.from_str_fraction::<i16>("XX").unwrap(),
+    Ratio::from(20)
+  );
+  assert_eq!(
+    roman.from_str_fraction::<u8>("VIIS").unwrap(),
+    Ratio::new(15, 2)
+  );
+  assert!(matches!(
+    roman.from_str_fraction::<u8>("\u{10195}"),
+    Err(ConversionError::Overflow)
+  ));
+}
+
+#[test]
+fn test_custom_with_s() {
+  let custom = Roman::new(&['R', 'S']);
+  assert_eq!(
+    custom.to_string_fraction(Ratio::new(23, 4)).unwrap(),
+    "S⁙·∴"
+  );
+  assert_eq!(
+    custom.from_str_fraction::<i32>("S⁙·∴").unwrap(),
+    Ratio::new(23, 4)
+  );
+}
+
+#[test]
+fn test_errors() {
+  let roman = Roman::default();
+  assert!(matches!(
+    roman.to_string_fraction(Ratio::new(1, 5)),
+    Err(ConversionError::InvalidFraction)
+  ));
+  assert!(matches!(
+    roman.to_string_fraction(Ratio::new(-1, 2)),
+    Err(ConversionError::NegativeNumber)
+  ));
+  assert!(matches!(
+    roman.from_str_fraction::<i32>("SI"),
+    Err(ConversionError::InvalidCharacter('S'))
+  ));
+}
@@ -0,0 +1,82 @@
+use romantic::{ConversionError, Roman};
+
+use test_case::test_case;
+
+#[test_case(1, "j"; "one")]
+#[test_case(3, "iij"; "three")]
+#[test_case(4, "iv"; "four")]
+#[test_case(8, "viij"; "eight")]
+#[test_case(13, "xiij"; "thirteen")]
+#[test_case(1999, "mcmxcix"; "subtractive ending")]
+fn test_round_trip(input: i32, expected: &str) {
+  let roman = Roman::default().with_minuscule(true).with_terminal_j(true);
+  assert_eq!(roman.to_string(input).unwrap(), expected);
+  assert_eq!(roman.from_str::<i32>(expected).unwrap(), input);
+  assert_eq!(roman.from_str_strict::<i32>(expected).unwrap(), input);
+}
+
+#[test_case("viii", 8; "without terminal j")]
+#[test_case("VIII", 8; "uppercase")]
+#[test_case("xIIj", 13; "mixed case")]
+fn test_from_str(input: &str, expected: i32) {
+  let roman = Roman::default().with_minuscule(true).with_terminal_j(true);
+  assert_eq!(roman.from_str::<i32>(input).unwrap(), expected);
+}
+
+#[test]
+fn test_uppercase_terminal_j() {
+  let roman = Roman::default().with_terminal_j(true);
+  assert_eq!(roman.to_string(8).unwrap(), "VIIJ");
+  assert_eq!(roman.from_str::<i32>("VIIJ").unwrap(), 8);
+}
+
+#[test]
+fn test_minuscule_without_terminal_j() {
+  let roman = Roman::default().with_minuscule(true);
+  assert_eq!(roman.to_string(8).unwrap(), "viii");
+  assert!(matches!(
+    roman.from_str::<i32>("viij"),
+    Err(ConversionError::InvalidCharacter('j'))
+  ));
+}
+
+#[test]
+fn test_minuscule_vinculum() {
+  let roman = Roman::default()
+    .with_minuscule(true)
+    .with_terminal_j(true)
+    .with_vinculum(true);
+  assert_eq!(roman.to_string(3001).unwrap(), "mmmj");
+  assert_eq!(roman.to_string(5001).unwrap(), "v\u{305}j");
+  assert_eq!(roman.from_str::<i32>("v\u{305}j").unwrap(), 5001);
+}
+
+#[test]
+fn test_errors() {
+  let roman = Roman::default().with_minuscule(true).with_terminal_j(true);
+  assert!(matches!(
+    roman.from_str::<i32>("ijj"),
+    Err(ConversionError::InvalidCharacter('j'))
+  ));
+  assert!(matches!(
+    roman.from_str::<i32>("viq"),
+    Err(ConversionError::InvalidCharacter('q'))
+  ));
+  assert!(matches!(
+    Roman::default().from_str::<i32>("viij"),
+    Err(ConversionError::InvalidCharacter('v'))
+  ));
+}
+
+#[test]
+fn test_nulla() {
+  let roman = Roman::default().with_nulla(true).with_minuscule(true);
+  assert_eq!(roman.to_string(0).unwrap(), "n");
+  assert_eq!(roman.from_str::<i32>("n").unwrap(), 0);
+  assert_eq!(roman.from_str::<i32>("N").unwrap(), 0);
+  assert_eq!(roman.from_bytes::<i32>(b"n").unwrap(), 0);
+  assert!(Roman::default()
+    .with_nulla(true)
+    .from_str::<i32>("n")
+    .is_err());
+}
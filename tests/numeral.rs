@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+
+use romantic::{
+  ConversionError, Notation, OverflowPolicy, Roman, RomanNumeral,
+};
+
+use test_case::test_case;
+
+#[test_case("XIV", 14; "fourteen")]
+#[test_case("MMMCMXCIX", 3999; "maximum")]
+#[test_case("IIII", 4; "additive")]
+fn test_from_str(input: &str, expected: i128) {
+  let numeral: RomanNumeral = input.parse().unwrap();
+  assert_eq!(numeral.value(), expected);
+}
+
+#[test_case(""; "empty")]
+#[test_case("XIZ"; "invalid character")]
+#[test_case("MMMM"; "too large")]
+fn test_from_str_errors(input: &str) {
+  assert!(input.parse::<RomanNumeral>().is_err());
+}
+
+#[test]
+fn test_try_from() {
+  assert_eq!(RomanNumeral::try_from(14_u8).unwrap().to_string(), "XIV");
+  assert_eq!(RomanNumeral::try_from(14_i64).unwrap().to_string(), "XIV");
+  assert_eq!(RomanNumeral::try_from(3999_usize).unwrap().value(), 3999);
+  assert!(matches!(
+    RomanNumeral::try_from(0),
+    Err(ConversionError::ZeroNumeral)
+  ));
+  assert!(matches!(
+    RomanNumeral::try_from(-1),
+    Err(ConversionError::NegativeNumber)
+  ));
+  assert!(RomanNumeral::try_from(4000_u32).is_err());
+  assert!(RomanNumeral::try_from(u128::MAX).is_err());
+}
+
+#[test]
+fn test_display() {
+  let numeral = RomanNumeral::try_from(1994).unwrap();
+  assert_eq!(format!("{numeral}"), "MCMXCIV");
+  assert_eq!(format!("{numeral:#}"), "mcmxciv");
+  assert_eq!(format!("{numeral:?}"), "RomanNumeral(1994)");
+}
+
+#[test]
+fn test_display_padding() {
+  let numeral = RomanNumeral::try_from(1994).unwrap();
+  let roman = Roman::default();
+  let display = roman.display(1994).unwrap();
+  assert_eq!(format!("{numeral:>10}"), "   MCMXCIV");
+  assert_eq!(format!("{numeral:-^11}"), "--MCMXCIV--");
+  assert_eq!(format!("{numeral:#9}"), "mcmxciv  ");
+  assert_eq!(format!("{numeral:>10}"), format!("{display:>10}"));
+  assert_eq!(format!("{numeral:-^11}"), format!("{display:-^11}"));
+}
+
+#[test]
+// Numerals only hash their value, not the lazily built tables of the system.
+#[allow(clippy::mutable_key_type)]
+fn test_custom_system() {
+  let additive = Roman::default().with_notation(Notation::Additive);
+  let a = additive.numeral_from_str("XIIII").unwrap();
+  let b: RomanNumeral = "XIV".parse().unwrap();
+  assert_eq!(a, b);
+  assert_eq!(a.to_string(), "XIIII");
+  assert_eq!(b.to_string(), "XIV");
+
+  let mut set = HashSet::new();
+  set.insert(a);
+  assert!(set.contains(&b));
+}
+
+#[test]
+fn test_nulla() {
+  let roman = Roman::default().with_nulla(true);
+  assert_eq!(roman.numeral(0).unwrap().to_string(), "N");
+}
+
+#[test]
+fn test_negative() {
+  let roman = Roman::default().with_negative_sign(Some("-"));
+  let numeral = roman.numeral(-14).unwrap();
+  assert_eq!(numeral.to_string(), "-XIV");
+  assert_eq!(roman.numeral_from_str("-XIV").unwrap(), numeral);
+}
+
+#[test]
+fn test_ordering() {
+  let mut numerals = ["X", "IX", "MMXXII", "IV", "XL"]
+    .iter()
+    .map(|input| input.parse::<RomanNumeral>().unwrap())
+    .collect::<Vec<_>>();
+  numerals.sort();
+
+  let sorted = numerals.iter().map(ToString::to_string).collect::<Vec<_>>();
+  assert_eq!(sorted, ["IV", "IX", "X", "XL", "MMXXII"]);
+  assert!(numerals[0] < numerals[1]);
+}
+
+#[test]
+fn test_arithmetic() {
+  let x = RomanNumeral::try_from(10).unwrap();
+  let iv = RomanNumeral::try_from(4).unwrap();
+
+  assert_eq!((x + iv).unwrap().to_string(), "XIV");
+  assert_eq!((x - iv).unwrap().to_string(), "VI");
+  assert_eq!((x * iv).unwrap().to_string(), "XL");
+  assert_eq!((x / iv).unwrap().to_string(), "II");
+
+  assert!((iv - x).is_err());
+  assert!((iv - iv).is_err());
+  assert!((iv / x).is_err());
+
+  let large = RomanNumeral::try_from(2000).unwrap();
+  assert!((large + large).is_err());
+  assert!((large * x).is_err());
+}
+
+#[test]
+fn test_arithmetic_system() {
+  let additive = Roman::default().with_notation(Notation::Additive);
+  let a = additive.numeral(4).unwrap();
+  let b = RomanNumeral::try_from(5).unwrap();
+  assert_eq!((a + b).unwrap().to_string(), "VIIII");
+  assert_eq!((b + a).unwrap().to_string(), "IX");
+}
+
+#[test_case(OverflowPolicy::Decimal; "decimal")]
+#[test_case(OverflowPolicy::Repeat; "repeat")]
+#[test_case(OverflowPolicy::Vinculum; "vinculum")]
+fn test_overflow_policy(policy: OverflowPolicy) {
+  let roman = Roman::default().with_overflow_policy(policy);
+  assert_eq!(roman.numeral(3999).unwrap().to_string(), "MMMCMXCIX");
+  assert!(matches!(
+    roman.numeral(4000),
+    Err(ConversionError::MissingMagnitude(5000))
+  ));
+  assert!(matches!(
+    roman.numeral(0),
+    Err(ConversionError::ZeroNumeral)
+  ));
+
+  let sum = roman.numeral(3000).unwrap() + roman.numeral(1000).unwrap();
+  assert!(sum.is_err());
+}
+
+#[test]
+fn test_vinculum() {
+  let roman = Roman::default().with_vinculum(true);
+  assert_eq!(roman.numeral(5001).unwrap().to_string(), "V\u{305}I");
+}
@@ -0,0 +1,115 @@
+use romantic::{ConversionError, OverflowPolicy, Roman};
+
+use test_case::test_case;
+
+#[test_case(OverflowPolicy::Decimal, 4000, "4000"; "decimal")]
+#[test_case(OverflowPolicy::Decimal, -4000, "-4000"; "decimal negative")]
+#[test_case(OverflowPolicy::Clamp, 4000, "MMMCMXCIX"; "clamp")]
+#[test_case(OverflowPolicy::Clamp, i64::MAX, "MMMCMXCIX"; "clamp maximum")]
+#[test_case(OverflowPolicy::Clamp, -4000, "-MMMCMXCIX"; "clamp negative")]
+#[test_case(OverflowPolicy::Repeat, 4000, "MMMM"; "repeat")]
+#[test_case(OverflowPolicy::Repeat, 6999, "MMMMMMCMXCIX"; "repeat remainder")]
+#[test_case(OverflowPolicy::Vinculum, 4000, "I\u{305}V\u{305}"; "vinculum")]
+#[test_case(OverflowPolicy::Vinculum, 4001, "I\u{305}V\u{305}I"; "vinculum remainder")]
+fn test_policies(policy: OverflowPolicy, input: i64, expected: &str) {
+  let roman = Roman::default()
+    .with_negative_sign(Some("-"))
+    .with_overflow_policy(policy);
+  assert_eq!(
+    roman.to_string_with_overflow(input).unwrap(),
+    (expected.to_string(), Some(policy))
+  );
+  assert_eq!(roman.to_string(input).unwrap(), expected);
+}
+
+#[test_case(OverflowPolicy::Error; "error")]
+#[test_case(OverflowPolicy::Decimal; "decimal")]
+#[test_case(OverflowPolicy::Clamp; "clamp")]
+#[test_case(OverflowPolicy::Repeat; "repeat")]
+#[test_case(OverflowPolicy::Vinculum; "vinculum")]
+fn test_in_range(policy: OverflowPolicy) {
+  let roman = Roman::default().with_overflow_policy(policy);
+  assert_eq!(
+    roman.to_string_with_overflow(3999).unwrap(),
+    ("MMMCMXCIX".to_string(), None)
+  );
+}
+
+#[test]
+fn test_error() {
+  assert!(matches!(
+    Roman::default().to_string_with_overflow(4000),
+    Err(ConversionError::MissingMagnitude(5000))
+  ));
+}
+
+#[test]
+fn test_styles() {
+  let roman = Roman::default()
+    .with_minuscule(true)
+    .with_terminal_j(true)
+    .with_overflow_policy(OverflowPolicy::Repeat);
+  assert_eq!(roman.to_string(5001).unwrap(), "mmmmmj");
+}
+
+#[test_case(5000, "V\u{305}"; "five thousand")]
+#[test_case(4001, "I\u{305}V\u{305}I"; "remainder")]
+fn test_vinculum_round_trip(input: i32, expected: &str) {
+  let roman = Roman::default().with_overflow_policy(OverflowPolicy::Vinculum);
+  assert_eq!(roman.to_string(input).unwrap(), expected);
+  assert_eq!(roman.from_str::<i32>(expected).unwrap(), input);
+  assert_eq!(roman.from_str_strict::<i32>(expected).unwrap(), input);
+  assert_eq!(roman.from_bytes::<i32>(expected.as_bytes()).unwrap(), input);
+}
+
+#[test]
+fn test_strict_repeat() {
+  let roman = Roman::default().with_overflow_policy(OverflowPolicy::Repeat);
+  assert_eq!(roman.from_str_strict::<i32>("MMMMM").unwrap(), 5000);
+  assert!(roman.from_str_strict::<i32>("MMMMMM").is_ok());
+  assert!(Roman::default().from_str_strict::<i32>("MMMMM").is_err());
+}
+
+#[test_case(&['A', 'B'], 100, "BAAA"; "custom")]
+#[test_case(&['I', 'V', 'X', 'L', 'C'], 1000, "CCCXCIX"; "without d")]
+fn test_clamp_custom(set: &[char], input: i32, expected: &str) {
+  let roman = Roman::new(set).with_overflow_policy(OverflowPolicy::Clamp);
+  assert_eq!(roman.to_string(input).unwrap(), expected);
+}
+
+#[test]
+fn test_clamp_vinculum() {
+  let roman = Roman::new(&['I', 'V', 'X'])
+    .with_vinculum(true)
+    .with_overflow_policy(OverflowPolicy::Clamp);
+  assert_eq!(
+    roman.to_string_with_overflow(39_039).unwrap(),
+    (
+      "X\u{305}X\u{305}X\u{305}I\u{305}X\u{305}XXXIX".to_string(),
+      None
+    )
+  );
+
+  let (result, overflow) = roman.to_string_with_overflow(u128::MAX).unwrap();
+  assert!(result.starts_with("X\u{305}\u{305}"));
+  assert_eq!(overflow, Some(OverflowPolicy::Clamp));
+}
+
+#[test]
+fn test_clamp_values() {
+  let roman = Roman::new_with_values(&[
+    ('I', 1),
+    ('V', 5),
+    ('X', 10),
+    ('L', 50),
+    ('C', 100),
+    ('ↁ', 5000),
+  ])
+  .unwrap()
+  .with_overflow_policy(OverflowPolicy::Clamp);
+  assert_eq!(roman.to_string(10_000).unwrap(), "ↁCCCXCIX");
+  assert!(matches!(
+    roman.to_string(1000),
+    Err(ConversionError::UnreachableValue(469))
+  ));
+}
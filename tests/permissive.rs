@@ -0,0 +1,50 @@
+use romantic::{Irregularity, Notation, Roman};
+
+use test_case::test_case;
+
+#[test_case("IIX", 8, &[Irregularity::RepeatedSubtraction('I', 2, 'X')]; "eight")]
+#[test_case("XXC", 80, &[Irregularity::RepeatedSubtraction('X', 2, 'C')]; "eighty")]
+#[test_case("IM", 999, &[Irregularity::IrregularSubtraction('I', 'M')]; "nine hundred ninety nine")]
+#[test_case("VL", 45, &[Irregularity::IrregularSubtraction('V', 'L')]; "forty five")]
+#[test_case("IC", 99, &[Irregularity::IrregularSubtraction('I', 'C')]; "ninety nine")]
+#[test_case("MIIXIIC", 1106, &[Irregularity::RepeatedSubtraction('I', 2, 'X'), Irregularity::RepeatedSubtraction('I', 2, 'C')]; "multiple")]
+#[test_case("MCMXCIV", 1994, &[]; "canonical")]
+#[test_case("IIII", 4, &[]; "additive")]
+fn test_permissive(
+  input: &str,
+  expected: i32,
+  irregularities: &[Irregularity],
+) {
+  let (number, applied) =
+    Roman::default().from_str_permissive::<i32>(input).unwrap();
+  assert_eq!(number, expected);
+  assert_eq!(applied, irregularities);
+}
+
+#[test]
+fn test_additive_notation() {
+  let roman = Roman::default().with_notation(Notation::Additive);
+  let (number, applied) = roman.from_str_permissive::<i32>("IV").unwrap();
+  assert_eq!(number, 4);
+  assert_eq!(applied, [Irregularity::IrregularSubtraction('I', 'V')]);
+}
+
+#[test]
+fn test_negative() {
+  let roman = Roman::default().with_negative_sign(Some("-"));
+  let (number, applied) = roman.from_str_permissive::<i32>("-IIX").unwrap();
+  assert_eq!(number, -8);
+  assert_eq!(applied.len(), 1);
+}
+
+#[test]
+fn test_unsigned() {
+  let (number, _) = Roman::default().from_str_permissive::<u8>("IIX").unwrap();
+  assert_eq!(number, 8);
+}
+
+#[test]
+fn test_errors() {
+  assert!(Roman::default().from_str_permissive::<i32>("IIZ").is_err());
+  assert!(Roman::default().from_str_permissive::<u8>("CCC").is_err());
+}
@@ -0,0 +1,80 @@
+use romantic::{ConstructionError, RomanBuilder};
+
+use test_case::test_case;
+
+#[test_case(1, "I"; "one")]
+#[test_case(5, "IS"; "five")]
+#[test_case(6, "S"; "six")]
+#[test_case(10, "SIIII"; "ten")]
+#[test_case(11, "IX"; "eleven")]
+#[test_case(12, "X"; "twelve")]
+#[test_case(143, "XCIX"; "one hundred forty three")]
+#[test_case(1727, "CMXCIX"; "maximum")]
+fn test_duodecimal(input: i32, expected: &str) {
+  let roman = RomanBuilder::new(&['I', 'S', 'X', 'L', 'C', 'D', 'M'])
+    .radix(12)
+    .pattern(&[1, 6])
+    .build()
+    .unwrap();
+  assert_eq!(roman.to_string(input).unwrap(), expected);
+  assert_eq!(roman.from_str_strict::<i32>(expected).unwrap(), input);
+}
+
+#[test_case(9, "IX"; "nine")]
+#[test_case(19, "IT"; "nineteen")]
+#[test_case(39, "TIT"; "thirty nine")]
+#[test_case(399, "TCIT"; "three hundred ninety nine")]
+fn test_vigesimal(input: i32, expected: &str) {
+  let roman = RomanBuilder::new(&['I', 'X', 'T', 'L', 'C'])
+    .radix(20)
+    .pattern(&[1, 10])
+    .build()
+    .unwrap();
+  assert_eq!(roman.to_string(input).unwrap(), expected);
+  assert_eq!(roman.from_str_strict::<i32>(expected).unwrap(), input);
+}
+
+#[test_case(1, "A"; "one")]
+#[test_case(2, "B"; "two")]
+#[test_case(5, "CA"; "five")]
+#[test_case(15, "DCBA"; "fifteen")]
+fn test_binary(input: i32, expected: &str) {
+  let roman = RomanBuilder::new(&['A', 'B', 'C', 'D'])
+    .radix(2)
+    .pattern(&[1])
+    .build()
+    .unwrap();
+  assert_eq!(roman.to_string(input).unwrap(), expected);
+  assert_eq!(roman.from_str_strict::<i32>(expected).unwrap(), input);
+}
+
+#[test]
+fn test_additive_duodecimal() {
+  let roman = RomanBuilder::new(&['I', 'S', 'X'])
+    .radix(12)
+    .pattern(&[1, 6])
+    .subtraction(false)
+    .build()
+    .unwrap();
+  assert_eq!(roman.to_string(11).unwrap(), "SIIIII");
+  assert_eq!(roman.from_str_strict::<i32>("SIIIII").unwrap(), 11);
+}
+
+#[test]
+fn test_default_radix() {
+  let roman = RomanBuilder::default().radix(10).pattern(&[1, 5]).build();
+  assert_eq!(roman.unwrap().to_string(1994).unwrap(), "MCMXCIV");
+}
+
+#[test_case(1, &[1], ConstructionError::InvalidRadix(1); "radix one")]
+#[test_case(10, &[], ConstructionError::InvalidPattern(10); "empty pattern")]
+#[test_case(10, &[2, 5], ConstructionError::InvalidPattern(10); "no unit")]
+#[test_case(10, &[1, 5, 5], ConstructionError::InvalidPattern(10); "not increasing")]
+#[test_case(2, &[1, 5], ConstructionError::InvalidPattern(2); "beyond radix")]
+fn test_errors(radix: usize, pattern: &[usize], expected: ConstructionError) {
+  let result = RomanBuilder::default()
+    .radix(radix)
+    .pattern(pattern)
+    .build();
+  assert_eq!(result.unwrap_err().to_string(), expected.to_string());
+}
@@ -0,0 +1,61 @@
+use romantic::{ConversionError, OverflowPolicy, Roman};
+
+use test_case::test_case;
+
+fn roman() -> Roman {
+  Roman::default().with_overflow_policy(OverflowPolicy::Repeat)
+}
+
+#[test_case(4000, "MMMM"; "four thousand")]
+#[test_case(6212, "MMMMMMCCXII"; "six thousand")]
+#[test_case(10_999, "MMMMMMMMMMCMXCIX"; "ten thousand")]
+fn test_repeat(input: u64, expected: &str) {
+  assert_eq!(roman().to_string(input).unwrap(), expected);
+  assert_eq!(roman().from_str::<u64>(expected).unwrap(), input);
+  assert_eq!(roman().from_str_strict::<u64>(expected).unwrap(), input);
+}
+
+#[test_case("MMMMDD"; "repeated five hundreds")]
+#[test_case("MMMMIIII"; "repeated units")]
+#[test_case("MMMCMM"; "out of order")]
+fn test_strict_errors(input: &str) {
+  assert!(roman().from_str_strict::<u64>(input).is_err());
+}
+
+#[test]
+fn test_default_limit() {
+  assert_eq!(roman().to_string(10_000_000).unwrap().len(), 10_000);
+  assert!(matches!(
+    roman().to_string(10_001_000),
+    Err(ConversionError::RepeatLimit(10_000))
+  ));
+  assert!(matches!(
+    roman().to_string(u64::MAX),
+    Err(ConversionError::RepeatLimit(10_000))
+  ));
+}
+
+#[test]
+fn test_custom_limit() {
+  let roman = roman().with_repeat_limit(Some(5));
+  assert_eq!(roman.to_string(5999).unwrap(), "MMMMMCMXCIX");
+  assert!(matches!(
+    roman.to_string(6000),
+    Err(ConversionError::RepeatLimit(5))
+  ));
+}
+
+#[test]
+fn test_without_limit() {
+  let roman = roman().with_repeat_limit(None);
+  assert_eq!(roman.to_string(20_000_000).unwrap().len(), 20_000);
+}
+
+#[test]
+fn test_custom_set() {
+  let roman = Roman::new(&['A', 'B'])
+    .with_overflow_policy(OverflowPolicy::Repeat)
+    .with_repeat_limit(Some(100));
+  assert_eq!(roman.to_string(14).unwrap(), "BBAB");
+  assert_eq!(roman.from_str_strict::<u64>("BBAB").unwrap(), 14);
+}
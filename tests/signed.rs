@@ -0,0 +1,107 @@
+use romantic::{ConversionError, Roman};
+
+use test_case::test_case;
+
+#[test_case(0, "N"; "zero")]
+#[test_case(14, "XIV"; "positive")]
+#[test_case(-14, "-XIV"; "negative")]
+#[test_case(-3999, "-MMMCMXCIX"; "minimum")]
+fn test_round_trip(input: i32, expected: &str) {
+  let roman = Roman::default()
+    .with_nulla(true)
+    .with_negative_sign(Some("-"));
+  assert_eq!(roman.to_string(input).unwrap(), expected);
+  assert_eq!(roman.from_str::<i32>(expected).unwrap(), input);
+  assert_eq!(roman.from_str_strict::<i32>(expected).unwrap(), input);
+}
+
+#[test_case(i8::MIN, "−CXXVIII"; "i8 minimum")]
+#[test_case(i16::MIN, "−X\u{305}X\u{305}X\u{305}I\u{305}I\u{305}DCCLXVIII"; "i16 minimum")]
+fn test_minimum<T>(input: T, expected: &str)
+where
+  T: num::PrimInt + ToString + std::fmt::Debug,
+{
+  let roman = Roman::default()
+    .with_vinculum(true)
+    .with_negative_sign(Some("−"));
+  assert_eq!(roman.to_string(input).unwrap(), expected);
+  assert_eq!(roman.from_str::<T>(expected).unwrap(), input);
+}
+
+#[test]
+fn test_defaults_unchanged() {
+  let roman = Roman::default();
+  assert_eq!(roman.to_string(0).unwrap(), "");
+  assert!(matches!(
+    roman.to_string(-1),
+    Err(ConversionError::NegativeNumber)
+  ));
+  assert!(matches!(
+    roman.from_str::<i32>("N"),
+    Err(ConversionError::InvalidCharacter('N'))
+  ));
+  assert!(matches!(
+    roman.from_str::<i32>("-I"),
+    Err(ConversionError::InvalidCharacter('-'))
+  ));
+}
+
+#[test]
+fn test_errors() {
+  let roman = Roman::default()
+    .with_nulla(true)
+    .with_negative_sign(Some("-"));
+  assert!(matches!(
+    roman.from_str::<u32>("-I"),
+    Err(ConversionError::NegativeNumber)
+  ));
+  assert!(matches!(
+    roman.from_str::<i8>("-CXXIX"),
+    Err(ConversionError::Overflow)
+  ));
+  assert!(matches!(
+    roman.from_str_strict::<i32>("-IIII"),
+    Err(ConversionError::InvalidRepetition('I'))
+  ));
+}
+
+#[test]
+fn test_empty_sign() {
+  let roman = Roman::default().with_negative_sign(Some(""));
+  assert_eq!(roman.from_str::<i32>("V").unwrap(), 5);
+  assert_eq!(roman.from_str::<u32>("V").unwrap(), 5);
+  assert!(matches!(
+    roman.to_string(-5),
+    Err(ConversionError::NegativeNumber)
+  ));
+}
+
+#[test]
+fn test_bare_sign() {
+  let roman = Roman::default()
+    .with_nulla(true)
+    .with_negative_sign(Some("-"));
+  assert!(matches!(
+    roman.from_str::<i32>("-"),
+    Err(ConversionError::MissingNumeral)
+  ));
+  assert!(matches!(
+    roman.from_str_permissive::<i32>("-"),
+    Err(ConversionError::MissingNumeral)
+  ));
+  assert_eq!(roman.from_str::<i32>("-N").unwrap(), 0);
+}
+
+#[test]
+fn test_nulla_in_character_set() {
+  let roman = Roman::new(&['N', 'O']).with_nulla(true);
+  assert_eq!(roman.to_string(0).unwrap(), "");
+  assert_eq!(roman.to_string(1).unwrap(), "N");
+  assert_eq!(roman.from_str::<i32>("N").unwrap(), 1);
+
+  let roman = Roman::new(&['n', 'o'])
+    .with_nulla(true)
+    .with_minuscule(true);
+  assert_eq!(roman.to_string(0).unwrap(), "");
+  assert_eq!(roman.from_str::<i32>("n").unwrap(), 1);
+}
@@ -0,0 +1,93 @@
+use romantic::{ConversionError, Roman};
+
+use test_case::test_case;
+
+#[test_case(499, ["CDXCIX", "LDVLIV", "XDIX", "VDIV", "ID"]; "four hundred ninety nine")]
+#[test_case(1999, ["MCMXCIX", "MLMVLIV", "MXMIX", "MVMIV", "MIM"]; "nineteen ninety nine")]
+#[test_case(45, ["XLV", "VL", "VL", "VL", "VL"]; "forty five")]
+#[test_case(990, ["CMXC", "LMXL", "XM", "XM", "XM"]; "nine hundred ninety")]
+#[test_case(0, ["", "", "", "", ""]; "zero")]
+fn test_forms(input: i32, expected: [&str; 5]) {
+  let roman = Roman::default();
+  for (form, expected) in expected.iter().enumerate() {
+    let output = roman.to_string_spreadsheet(input, form as u8).unwrap();
+    assert_eq!(&output, expected);
+    assert_eq!(roman.from_str_spreadsheet::<i32>(expected).unwrap(), input);
+  }
+}
+
+#[test]
+fn test_classic_form() {
+  let roman = Roman::default();
+  for number in 1..=3999 {
+    assert_eq!(
+      roman.to_string_spreadsheet(number, 0).unwrap(),
+      roman.to_string(number).unwrap()
+    );
+  }
+}
+
+#[test]
+fn test_round_trip() {
+  let roman = Roman::default();
+  for form in 0..=4 {
+    for number in 0..=3999 {
+      let output = roman.to_string_spreadsheet(number, form).unwrap();
+      assert_eq!(roman.from_str_spreadsheet::<i32>(&output).unwrap(), number);
+    }
+  }
+}
+
+#[test_case("-XIV", -14; "negative")]
+#[test_case("xiv", 14; "lowercase")]
+#[test_case("IIII", 4; "additive")]
+#[test_case("", 0; "empty")]
+fn test_arabic(input: &str, expected: i32) {
+  assert_eq!(
+    Roman::default().from_str_spreadsheet::<i32>(input).unwrap(),
+    expected
+  );
+}
+
+#[test]
+fn test_errors() {
+  let roman = Roman::default();
+  assert!(matches!(
+    roman.to_string_spreadsheet(10, 5),
+    Err(ConversionError::InvalidForm(5))
+  ));
+  assert!(matches!(
+    roman.to_string_spreadsheet(4000, 0),
+    Err(ConversionError::Overflow)
+  ));
+  assert!(matches!(
+    roman.to_string_spreadsheet(-1, 0),
+    Err(ConversionError::NegativeNumber)
+  ));
+  assert!(matches!(
+    roman.from_str_spreadsheet::<u32>("-X"),
+    Err(ConversionError::NegativeNumber)
+  ));
+  assert!(matches!(
+    roman.from_str_spreadsheet::<i32>("XZ"),
+    Err(ConversionError::InvalidCharacter('Z'))
+  ));
+  assert!(matches!(
+    roman.from_str_spreadsheet::<i32>("-"),
+    Err(ConversionError::MissingNumeral)
+  ));
+}
+
+#[test]
+fn test_custom_case() {
+  let roman = Roman::new(&['i', 'v', 'x', 'l', 'c', 'd', 'm']);
+  for form in 0..=4 {
+    let output = roman.to_string_spreadsheet(1999, form).unwrap();
+    assert_eq!(roman.from_str_spreadsheet::<i32>(&output).unwrap(), 1999);
+  }
+
+  let roman = Roman::new(&['A', 'a', 'B', 'b', 'C', 'c', 'D']);
+  assert_eq!(roman.to_string_spreadsheet(16, 0).unwrap(), "BaA");
+  assert_eq!(roman.from_str_spreadsheet::<i32>("BaA").unwrap(), 16);
+  assert_eq!(roman.from_str_spreadsheet::<i32>("bA").unwrap(), 51);
+}
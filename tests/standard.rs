@@ -0,0 +1,56 @@
+use romantic::{encode_default, write_default, ConversionError, Roman};
+
+use test_case::test_case;
+
+#[test]
+fn test_roman_equivalence() {
+  let roman = Roman::default();
+  let mut buffer = [0; 15];
+  for number in 0..=20000_u32 {
+    let expected = roman.to_string(number).map_err(|error| error.to_string());
+
+    let mut output = String::new();
+    let written = write_default(&mut output, number)
+      .map(|_| output)
+      .map_err(|error| error.to_string());
+    assert_eq!(written, expected, "{number}");
+
+    let encoded = encode_default(&mut buffer, number)
+      .map(str::to_string)
+      .map_err(|error| error.to_string());
+    assert_eq!(encoded, expected, "{number}");
+  }
+}
+
+#[test_case(40_000_u128; "ten thousands")]
+#[test_case(95_000_000_u128; "subtractive")]
+#[test_case(u64::MAX as u128; "u64")]
+#[test_case(u128::MAX; "u128")]
+fn test_overflow_equivalence(number: u128) {
+  let expected = Roman::default().to_string(number).unwrap_err();
+  let error = write_default(&mut String::new(), number).unwrap_err();
+  assert_eq!(error.to_string(), expected.to_string());
+}
+
+#[test]
+fn test_negative() {
+  assert!(matches!(
+    write_default(&mut String::new(), -14),
+    Err(ConversionError::NegativeNumber)
+  ));
+}
+
+#[test_case(0; "empty")]
+#[test_case(7; "partial")]
+#[test_case(14; "one short")]
+fn test_buffer_too_small(size: usize) {
+  let mut buffer = [0; 15];
+  assert!(matches!(
+    encode_default(&mut buffer[..size], 3888),
+    Err(ConversionError::BufferTooSmall(15))
+  ));
+  assert_eq!(
+    encode_default(&mut buffer, 3888).unwrap(),
+    "MMMDCCCLXXXVIII"
+  );
+}
@@ -0,0 +1,57 @@
+use romantic::{ConversionError, Roman};
+
+use test_case::test_case;
+
+#[test_case("", 0; "empty")]
+#[test_case("XIV", 14; "fourteen")]
+#[test_case("XIX", 19; "nineteen")]
+#[test_case("XC", 90; "ninety")]
+#[test_case("MMMDCCCLXXXVIII", 3888; "complicated")]
+#[test_case("MMMCMXCIX", 3999; "maximum")]
+fn test_from_str_strict(input: &str, expected: u16) {
+  assert_eq!(
+    Roman::default().from_str_strict::<u16>(input).unwrap(),
+    expected
+  );
+}
+
+#[test_case("IIII", 'I'; "four units")]
+#[test_case("VV", 'V'; "two fives")]
+#[test_case("MMMMM", 'M'; "five thousands")]
+#[test_case("XXXXI", 'X'; "four tens")]
+fn test_invalid_repetition(input: &str, expected: char) {
+  assert!(matches!(
+    Roman::default().from_str_strict::<i32>(input),
+    Err(ConversionError::InvalidRepetition(character)) if character == expected
+  ));
+}
+
+#[test_case("VX", ('V', 'X'); "five from ten")]
+#[test_case("IC", ('I', 'C'); "one from hundred")]
+#[test_case("VL", ('V', 'L'); "five from fifty")]
+#[test_case("XM", ('X', 'M'); "ten from thousand")]
+fn test_invalid_subtraction(input: &str, expected: (char, char)) {
+  assert!(matches!(
+    Roman::default().from_str_strict::<i32>(input),
+    Err(ConversionError::InvalidSubtraction(a, b)) if (a, b) == expected
+  ));
+}
+
+#[test_case("IIV", 'I'; "repeated subtraction")]
+#[test_case("VIV", 'V'; "five before four")]
+#[test_case("IXI", 'I'; "unit after nine")]
+#[test_case("IXX", 'I'; "ascending")]
+#[test_case("XCX", 'X'; "ten after ninety")]
+fn test_invalid_order(input: &str, expected: char) {
+  assert!(matches!(
+    Roman::default().from_str_strict::<i32>(input),
+    Err(ConversionError::InvalidOrder(character)) if character == expected
+  ));
+}
+
+#[test_case("C"; "invalid character")]
+#[test_case("BAB"; "not representable")]
+fn test_custom_strict_error(input: &str) {
+  let custom = Roman::new(&['A', 'B']);
+  assert!(custom.from_str_strict::<i32>(input).is_err());
+}
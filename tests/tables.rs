@@ -0,0 +1,105 @@
+use romantic::{Notation, Roman, RomanBuilder};
+
+use test_case::test_case;
+
+/// Returns the same [`Roman`] with and without a lookup table.
+fn with_and_without(create: fn() -> Roman) -> (Roman, Roman) {
+  (create().with_lookup_table(true), create())
+}
+
+#[test_case(Roman::default; "default")]
+#[test_case(|| Roman::default().with_minuscule(true).with_terminal_j(true); "styled")]
+#[test_case(|| Roman::default().with_unicode_output(true); "unicode")]
+#[test_case(|| Roman::default().with_apostrophus(true); "apostrophus")]
+#[test_case(|| Roman::default().with_notation(Notation::Additive); "additive")]
+#[test_case(|| Roman::new(&['A', 'B', 'C', 'D']); "custom")]
+fn test_lookup_table(create: fn() -> Roman) {
+  let (lookup, plain) = with_and_without(create);
+  for number in 0..=4000 {
+    assert_eq!(
+      lookup.to_string(number).ok(),
+      plain.to_string(number).ok(),
+      "{number}"
+    );
+  }
+}
+
+#[test]
+fn test_lookup_table_rules() {
+  let roman = Roman::default()
+    .with_lookup_table(true)
+    .with_notation(Notation::Additive)
+    .with_vinculum(true);
+  assert_eq!(roman.to_string(4).unwrap(), "IIII");
+  assert_eq!(roman.to_string(4999).unwrap(), "MMMMDCCCCLXXXXVIIII");
+  assert_eq!(roman.to_string(5001).unwrap(), "V\u{305}I");
+
+  let roman = Roman::default()
+    .with_lookup_table(true)
+    .with_lookup_table(false);
+  assert_eq!(roman.to_string(1984).unwrap(), "MCMLXXXIV");
+}
+
+#[test_case(&[('I', 1), ('V', 5), ('X', 10), ('C', 100)]; "values")]
+#[test_case(&[('I', 1), ('V', 5), ('X', 10), ('L', 50), ('ↁ', 5000)]; "gap")]
+fn test_lookup_table_values(values: &[(char, usize)]) {
+  let lookup = Roman::new_with_values(values)
+    .unwrap()
+    .with_lookup_table(true);
+  let plain = Roman::new_with_values(values).unwrap();
+  for number in 0..=6000 {
+    assert_eq!(
+      lookup.to_string(number).ok(),
+      plain.to_string(number).ok(),
+      "{number}"
+    );
+  }
+}
+
+#[test_case(Roman::default; "default")]
+#[test_case(|| Roman::default().with_minuscule(true); "minuscule")]
+#[test_case(|| Roman::new(&['é', 'Ð', 'ü']); "non-ascii")]
+fn test_without_tables(create: fn() -> Roman) {
+  let tabled = create().with_nulla(true);
+  let untabled = create().with_tables(false).with_nulla(true);
+  for number in 0..=4000 {
+    let numeral = tabled.to_string(number).ok();
+    assert_eq!(untabled.to_string(number).ok(), numeral, "{number}");
+
+    if let Some(numeral) = numeral {
+      assert_eq!(untabled.from_str::<i32>(&numeral).unwrap(), number);
+      let bytes = numeral.as_bytes();
+      assert_eq!(untabled.from_bytes::<i32>(bytes).unwrap(), number);
+    }
+  }
+}
+
+#[test]
+fn test_large_radix() {
+  let roman = RomanBuilder::new(&['I', 'L', 'C', 'M'])
+    .radix(100)
+    .pattern(&[1, 50])
+    .maximum_repetitions(49)
+    .build()
+    .unwrap();
+  assert_eq!(roman.to_string(101).unwrap(), "CI");
+  assert_eq!(roman.from_str::<i32>("CI").unwrap(), 101);
+}
+
+#[test_case("MMXXII", false; "ascii")]
+#[test_case("mmxxii", true; "minuscule")]
+#[test_case("ⅯⅯⅩⅩⅠⅠ", false; "unicode")]
+fn test_decode(input: &str, minuscule: bool) {
+  let roman = Roman::default()
+    .with_minuscule(minuscule)
+    .with_unicode_input(true);
+  assert_eq!(roman.from_str::<i32>(input).unwrap(), 2022);
+}
+
+#[test]
+fn test_decode_non_ascii_set() {
+  let roman = Roman::new(&['é', 'Ð', 'ü']);
+  assert_eq!(roman.to_string(9).unwrap(), "éü");
+  assert_eq!(roman.from_str::<i32>("Ðé").unwrap(), 6);
+  assert!(roman.from_str::<i32>("ÐI").is_err());
+}
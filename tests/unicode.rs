@@ -0,0 +1,96 @@
+use romantic::{ConversionError, Notation, Roman, RomanBuilder};
+
+use test_case::test_case;
+
+#[test_case("Ⅰ", 1; "one")]
+#[test_case("Ⅻ", 12; "twelve ligature")]
+#[test_case("ⅹⅱ", 12; "lowercase")]
+#[test_case("ⅩⅫ", 22; "ligature after ten")]
+#[test_case("MMXⅫ", 2022; "mixed with ascii")]
+#[test_case("ⅯⅭⅯⅩⅬⅣ", 1944; "subtractive")]
+#[test_case("ⅡⅩ", 12; "ligature never subtracts")]
+#[test_case("ↀↀ", 2000; "old thousand")]
+#[test_case("ↈↇↂↁⅯ", 166_000; "large")]
+#[test_case("ↆↅ", 56; "early forms")]
+fn test_from_str(input: &str, expected: u32) {
+  let roman = Roman::default().with_unicode_input(true);
+  assert_eq!(roman.from_str::<u32>(input).unwrap(), expected);
+}
+
+#[test_case(1, "Ⅰ"; "one")]
+#[test_case(4, "Ⅳ"; "four")]
+#[test_case(11, "Ⅺ"; "eleven")]
+#[test_case(12, "Ⅻ"; "twelve")]
+#[test_case(13, "ⅩⅠⅠⅠ"; "thirteen")]
+#[test_case(1999, "ⅯⅭⅯⅩⅭⅠⅩ"; "nineteen ninety nine")]
+#[test_case(399_999, "ↈↈↈↂↈⅯↂⅭⅯⅩⅭⅠⅩ"; "maximum")]
+fn test_to_string(input: u32, expected: &str) {
+  let roman = Roman::default()
+    .with_unicode_input(true)
+    .with_unicode_output(true);
+  assert_eq!(roman.to_string(input).unwrap(), expected);
+  assert_eq!(roman.from_str_strict::<u32>(expected).unwrap(), input);
+}
+
+#[test]
+fn test_custom_output() {
+  let custom = Roman::new(&['A', 'B']).with_unicode_output(true);
+  assert_eq!(custom.to_string(9).unwrap(), "Ⅸ");
+  assert_eq!(custom.to_string(1000).unwrap(), "Ⅿ");
+}
+
+#[test]
+fn test_additive_ligatures() {
+  let roman = Roman::default()
+    .with_notation(Notation::Additive)
+    .with_unicode_input(true)
+    .with_unicode_output(true);
+  assert_eq!(roman.to_string(4).unwrap(), "ⅠⅠⅠⅠ");
+  assert_eq!(roman.to_string(9).unwrap(), "ⅤⅠⅠⅠⅠ");
+  assert_eq!(roman.to_string(12).unwrap(), "Ⅻ");
+  assert_eq!(roman.from_str_strict::<i32>("ⅠⅠⅠⅠ").unwrap(), 4);
+  assert!(roman.from_str_strict::<i32>("Ⅳ").is_err());
+  assert!(roman.from_str_strict::<i32>("Ⅸ").is_err());
+}
+
+#[test]
+fn test_builder_ligatures() {
+  let roman = RomanBuilder::new(&['I', 'V', 'X', 'L', 'C', 'D', 'M'])
+    .subtraction(false)
+    .build()
+    .unwrap()
+    .with_unicode_output(true);
+  assert_eq!(roman.to_string(4).unwrap(), "ⅠⅠⅠⅠ");
+  assert_eq!(roman.to_string(8).unwrap(), "Ⅷ");
+}
+
+#[test]
+fn test_extended_maximum() {
+  let plain = Roman::default();
+  let unicode = Roman::default().with_unicode_output(true);
+  assert!(plain.to_string(4000).is_err());
+  assert_eq!(unicode.to_string(4000).unwrap(), "Ⅿↁ");
+  assert_eq!(unicode.to_string(399_999).unwrap(), "ↈↈↈↂↈⅯↂⅭⅯⅩⅭⅠⅩ");
+  assert!(unicode.to_string(400_000).is_err());
+}
+
+#[test]
+fn test_errors() {
+  assert!(matches!(
+    Roman::default().from_str::<i32>("Ⅻ"),
+    Err(ConversionError::InvalidCharacter('Ⅻ'))
+  ));
+
+  let roman = Roman::default()
+    .with_unicode_input(true)
+    .with_unicode_output(true);
+  assert!(roman.to_string(400_000).is_err());
+  assert!(matches!(
+    roman.from_str_strict::<i32>("ⅫⅫ"),
+    Err(ConversionError::InvalidRepetition('Ⅻ'))
+  ));
+  assert!(matches!(
+    roman.from_str_strict::<i32>("ⅩⅡ"),
+    Err(ConversionError::InvalidOrder('Ⅹ'))
+  ));
+}
@@ -0,0 +1,99 @@
+use romantic::{
+  ConstructionError, ConversionError, Notation, Roman, RomanBuilder,
+};
+
+use test_case::test_case;
+
+fn roman() -> Roman {
+  Roman::new_with_values(&[
+    ('I', 1),
+    ('V', 5),
+    ('X', 10),
+    ('L', 50),
+    ('C', 100),
+    ('ↁ', 5000),
+  ])
+  .unwrap()
+}
+
+#[test_case(4, "IV"; "four")]
+#[test_case(49, "XLIX"; "forty nine")]
+#[test_case(399, "CCCXCIX"; "three hundred ninety nine")]
+#[test_case(5000, "ↁ"; "five thousand")]
+#[test_case(5399, "ↁCCCXCIX"; "maximum")]
+fn test_values(input: i32, expected: &str) {
+  assert_eq!(roman().to_string(input).unwrap(), expected);
+  assert_eq!(roman().from_str::<i32>(expected).unwrap(), input);
+  assert_eq!(roman().from_str_strict::<i32>(expected).unwrap(), input);
+}
+
+#[test_case(1000, 469; "thousand")]
+#[test_case(10_000, 4469; "ten thousand")]
+fn test_unreachable(input: i32, expected: u128) {
+  assert!(matches!(
+    roman().to_string(input),
+    Err(ConversionError::UnreachableValue(value)) if value == expected
+  ));
+}
+
+#[test_case(&[('A', 1), ('B', 3), ('C', 7)], 6, "BAAA"; "remainder")]
+#[test_case(&[('A', 1), ('B', 3), ('C', 7)], 13, "CBAAA"; "larger remainder")]
+#[test_case(&[('I', 1), ('V', 5), ('X', 10), ('C', 100)], 400, "CCCXCX"; "missing character")]
+fn test_greedy_remainder(values: &[(char, usize)], input: i32, expected: &str) {
+  let roman = Roman::new_with_values(values).unwrap();
+  assert_eq!(roman.to_string(input).unwrap(), expected);
+  assert_eq!(roman.from_str::<i32>(expected).unwrap(), input);
+}
+
+#[test]
+fn test_missing_unit() {
+  let roman = Roman::new_with_values(&[('V', 5), ('X', 10)]).unwrap();
+  assert_eq!(roman.to_string(15).unwrap(), "XV");
+  assert!(matches!(
+    roman.to_string(16),
+    Err(ConversionError::UnreachableValue(1))
+  ));
+}
+
+#[test]
+fn test_irregular_values() {
+  let roman =
+    Roman::new_with_values(&[('I', 1), ('Q', 4), ('S', 7), ('X', 10)]).unwrap();
+  assert_eq!(roman.to_string(9).unwrap(), "IX");
+  assert_eq!(roman.to_string(8).unwrap(), "SI");
+  assert_eq!(roman.to_string(25).unwrap(), "XXQI");
+  assert_eq!(roman.from_str::<i32>("XXQI").unwrap(), 25);
+}
+
+#[test]
+fn test_additive() {
+  let roman = roman().with_notation(Notation::Additive);
+  assert_eq!(roman.to_string(49).unwrap(), "XXXXVIIII");
+  assert_eq!(roman.from_str_strict::<i32>("XXXXVIIII").unwrap(), 49);
+}
+
+#[test]
+fn test_vinculum() {
+  let roman = roman().with_vinculum(true);
+  assert_eq!(roman.to_string(10_000).unwrap(), "X\u{305}");
+}
+
+#[test]
+fn test_builder() {
+  let roman = RomanBuilder::new_with_values(&[('I', 1), ('V', 5), ('X', 10)])
+    .subtraction(false)
+    .build()
+    .unwrap();
+  assert_eq!(roman.to_string(9).unwrap(), "VIIII");
+}
+
+#[test_case(&[], ConstructionError::EmptyCharacterSet; "empty")]
+#[test_case(&[('I', 1), ('I', 5)], ConstructionError::DuplicateCharacter('I'); "duplicate character")]
+#[test_case(&[('I', 1), ('J', 1)], ConstructionError::DuplicateValue('J'); "duplicate value")]
+#[test_case(&[('I', 0)], ConstructionError::ZeroValue('I'); "zero")]
+fn test_errors(input: &[(char, usize)], expected: ConstructionError) {
+  assert_eq!(
+    Roman::new_with_values(input).unwrap_err().to_string(),
+    expected.to_string()
+  );
+}
@@ -0,0 +1,43 @@
+use romantic::{ConversionError, Roman};
+
+use test_case::test_case;
+
+#[test_case(3999, "MMMCMXCIX"; "no vinculum")]
+#[test_case(4000, "I\u{305}V\u{305}"; "four thousand")]
+#[test_case(5000, "V\u{305}"; "five thousand")]
+#[test_case(12_345, "X\u{305}I\u{305}I\u{305}CCCXLV"; "twelve thousand")]
+#[test_case(3_999_999, "M\u{305}M\u{305}M\u{305}C\u{305}M\u{305}X\u{305}C\u{305}I\u{305}X\u{305}CMXCIX"; "largest single bar")]
+#[test_case(4_000_000, "I\u{305}\u{305}V\u{305}\u{305}"; "double bar")]
+#[test_case(1_234_567_890, "M\u{305}\u{305}C\u{305}\u{305}C\u{305}\u{305}X\u{305}\u{305}X\u{305}\u{305}X\u{305}\u{305}I\u{305}\u{305}V\u{305}\u{305}D\u{305}L\u{305}X\u{305}V\u{305}I\u{305}I\u{305}DCCCXC"; "billions")]
+fn test_round_trip(input: u64, expected: &str) {
+  let roman = Roman::default().with_vinculum(true);
+  assert_eq!(roman.to_string(input).unwrap(), expected);
+  assert_eq!(roman.from_str::<u64>(expected).unwrap(), input);
+  assert_eq!(roman.from_str_strict::<u64>(expected).unwrap(), input);
+}
+
+#[test]
+fn test_custom() {
+  let custom =
+    Roman::new(&['A', 'B', 'C', 'D', 'E', 'F', 'G']).with_vinculum(true);
+  assert_eq!(custom.to_string(5001).unwrap(), "B\u{305}A");
+  assert_eq!(custom.from_str::<i32>("B\u{305}A").unwrap(), 5001);
+}
+
+#[test]
+fn test_errors() {
+  let roman = Roman::default().with_vinculum(true);
+  assert!(matches!(
+    roman.from_str::<i32>("\u{305}I"),
+    Err(ConversionError::InvalidCharacter('\u{305}'))
+  ));
+  assert!(matches!(
+    roman.from_str_strict::<i32>("I\u{305}I\u{305}I\u{305}I\u{305}"),
+    Err(ConversionError::InvalidRepetition('I'))
+  ));
+  assert!(matches!(
+    Roman::default().from_str::<i32>("V\u{305}"),
+    Err(ConversionError::InvalidCharacter('\u{305}'))
+  ));
+  assert!(Roman::default().to_string(4000).is_err());
+}
@@ -0,0 +1,178 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::{self, Write};
+use std::io;
+
+use romantic::{ConversionError, OverflowPolicy, Roman};
+
+use test_case::test_case;
+
+/// An allocator counting the allocations made on each thread.
+struct CountingAllocator;
+
+thread_local! {
+  static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    ALLOCATIONS.with(|count| count.set(count.get() + 1));
+    System.alloc(layout)
+  }
+
+  unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+    System.dealloc(pointer, layout)
+  }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// A [`fmt::Write`] output with a fixed capacity that never allocates.
+struct Buffer {
+  bytes: [u8; 64],
+  length: usize,
+}
+
+impl Buffer {
+  fn new() -> Self {
+    Self {
+      bytes: [0; 64],
+      length: 0,
+    }
+  }
+
+  fn as_str(&self) -> &str {
+    std::str::from_utf8(&self.bytes[..self.length]).unwrap()
+  }
+}
+
+impl Write for Buffer {
+  fn write_str(&mut self, string: &str) -> fmt::Result {
+    let end = self.length + string.len();
+    self.bytes.get_mut(self.length..end).ok_or(fmt::Error)?;
+    self.bytes[self.length..end].copy_from_slice(string.as_bytes());
+    self.length = end;
+    Ok(())
+  }
+}
+
+#[test]
+fn test_display() {
+  let roman = Roman::default().with_negative_sign(Some("-"));
+  let display = roman.display(14).unwrap();
+  assert_eq!(format!("{display}"), "XIV");
+  assert_eq!(format!("{display:8}"), "XIV     ");
+  assert_eq!(format!("{display:<8}"), "XIV     ");
+  assert_eq!(format!("{display:>8}"), "     XIV");
+  assert_eq!(format!("{display:^8}"), "  XIV   ");
+  assert_eq!(format!("{display:*>8}"), "*****XIV");
+  assert_eq!(format!("{display:2}"), "XIV");
+  assert_eq!(format!("{:>6}", roman.display(-14).unwrap()), "  -XIV");
+}
+
+#[test]
+fn test_display_unicode_width() {
+  let roman = Roman::default().with_vinculum(true);
+  assert_eq!(
+    format!("{:>5}", roman.display(5000).unwrap()),
+    "   V\u{305}"
+  );
+}
+
+#[test]
+fn test_display_does_not_allocate() {
+  let roman = Roman::default()
+    .with_minuscule(true)
+    .with_terminal_j(true)
+    .with_vinculum(true);
+  let mut buffer = Buffer::new();
+
+  let before = ALLOCATIONS.with(Cell::get);
+  let display = roman.display(4001).unwrap();
+  write!(buffer, "[{display:>8}]").unwrap();
+  roman.write_to(&mut buffer, 3).unwrap();
+  let after = ALLOCATIONS.with(Cell::get);
+
+  assert_eq!(buffer.as_str(), "[   i\u{305}v\u{305}j]iij");
+  assert_eq!(after - before, 0);
+}
+
+#[test]
+fn test_display_error() {
+  let roman = Roman::default();
+  assert!(matches!(
+    roman.display(4000),
+    Err(ConversionError::MissingMagnitude(5000))
+  ));
+  assert!(matches!(
+    roman.display(-1),
+    Err(ConversionError::NegativeNumber)
+  ));
+}
+
+#[test]
+fn test_display_output_error() {
+  let mut buffer = Buffer::new();
+  let roman = Roman::default();
+  let display = roman.display(3888).unwrap();
+  for _ in 0..4 {
+    write!(buffer, "{display}").unwrap();
+  }
+  assert!(write!(buffer, "{display}").is_err());
+}
+
+#[test_case(2022, "MMXXII"; "default")]
+#[test_case(0, ""; "zero")]
+fn test_write_to(number: u32, expected: &str) {
+  let mut output = String::new();
+  Roman::default().write_to(&mut output, number).unwrap();
+  assert_eq!(output, expected);
+}
+
+#[test]
+fn test_write_to_overflow() {
+  let roman = Roman::default().with_overflow_policy(OverflowPolicy::Decimal);
+  let mut buffer = Buffer::new();
+  roman.write_to(&mut buffer, 12_345).unwrap();
+  assert_eq!(buffer.as_str(), "12345");
+}
+
+#[test]
+fn test_write_to_error() {
+  let mut output = String::from("unchanged");
+  assert!(matches!(
+    Roman::default().write_to(&mut output, 4999),
+    Err(ConversionError::MissingMagnitude(5000))
+  ));
+  assert_eq!(output, "unchanged");
+
+  let mut buffer = Buffer::new();
+  let roman = Roman::default().with_overflow_policy(OverflowPolicy::Repeat);
+  assert!(matches!(
+    roman.write_to(&mut buffer, 100_000),
+    Err(ConversionError::Format(_))
+  ));
+}
+
+#[test]
+fn test_write_to_io() {
+  let mut output = Vec::new();
+  let roman = Roman::default().with_minuscule(true);
+  roman.write_to_io(&mut output, 1984).unwrap();
+  assert_eq!(output, b"mcmlxxxiv");
+
+  let mut unchanged = Vec::new();
+  assert!(roman.write_to_io(&mut unchanged, -1).is_err());
+  assert!(unchanged.is_empty());
+}
+
+#[test]
+fn test_write_to_io_error() {
+  let mut output = [0_u8; 3];
+  let result = Roman::default().write_to_io(&mut &mut output[..], 3888);
+  assert!(matches!(
+    result,
+    Err(ConversionError::Io(error)) if error.kind() == io::ErrorKind::WriteZero
+  ));
+}